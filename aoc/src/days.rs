@@ -0,0 +1,42 @@
+use support::{parse::ParseError, solution::run};
+
+/// Every day that has a crate in the workspace, in the order `aoc run` goes through them.
+pub const DAYS: [u8; 17] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 16, 19, 20, 25];
+
+/// Runs one part of one day on the given input.
+/// Days that implement `Solution` go through the generic runner; the rest are still called by hand.
+/// Returns `Ok(None)` if there is no solver for that day/part.
+pub fn solve(day: u8, part: u8, input: &str)->Result<Option<String>, ParseError>{
+    let answer = match (day, part){
+        (1, _) => return run::<d1::Day1>(part, input),
+        (2, _) => return run::<d2::Day2>(part, input),
+        (3, _) => return run::<d3::Day3>(part, input),
+        (4, 1) => d4::solve1(&d4::get_matrix_from_string(input)?).to_string(),
+        (4, 2) => d4::solve2(&d4::get_matrix_from_string(input)?).to_string(),
+        (5, _) => return run::<d5::Day5>(part, input),
+        (6, 1) => d6::Matrix::from_string(input)?.solve1().to_string(),
+        (6, 2) => d6::Matrix::from_string(input)?.solve2().to_string(),
+        (7, _) => return run::<d7::Day7>(part, input),
+        (8, 1) => {
+            let (data, builder) = d8::get_data(input)?;
+            d8::solve1(&data, &builder).to_string()
+        },
+        (8, 2) => {
+            let (data, builder) = d8::get_data(input)?;
+            d8::solve2(&data, &builder).to_string()
+        },
+        (9, 1) => d9::solve1(&d9::get_data(input)?).to_string(),
+        (9, 2) => d9::solve2(&d9::get_data(input)?).to_string(),
+        (10, _) => return run::<d10::Day10>(part, input),
+        (11, _) => return run::<d11::Day11>(part, input),
+        (12, 1) => d12::solve1(&d12::get_data(input)?).to_string(),
+        (12, 2) => d12::solve2(&d12::get_data(input)?).to_string(),
+        (13, _) => return run::<d13::Day13>(part, input),
+        (16, _) => return run::<d16::Day16>(part, input),
+        (19, _) => return run::<d19::Day19>(part, input),
+        (20, _) => return run::<d20::Day20>(part, input),
+        (25, _) => return run::<d25::Day25>(part, input),
+        _ => return Ok(None),
+    };
+    Ok(Some(answer))
+}
@@ -0,0 +1,146 @@
+mod days;
+
+use std::{fs::read_to_string, path::{Path, PathBuf}, process::ExitCode, time::Instant};
+
+use support::answers;
+
+const USAGE: &str = "\
+Usage: aoc run [--day <day>] [--part <1|2>] [--input <path>]
+
+Without --day every day is run. Without --part both parts are run.
+The input defaults to d<day>/Data.txt in the workspace root.
+Every answer is checked against answers.toml in the workspace root.";
+
+#[derive(Debug, Default, PartialEq)]
+struct RunArgs{
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+}
+
+fn parse_number(flag: &str, value: Option<String>, valid: impl Fn(u8)->bool)->Result<u8, String>{
+    let value = value.ok_or(format!("{flag} needs a value"))?;
+    match value.parse::<u8>(){
+        Ok(n) if valid(n) => Ok(n),
+        _ => Err(format!("Invalid value for {flag}: \"{value}\"")),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>)->Result<RunArgs, String>{
+    match args.next().as_deref(){
+        Some("run") => (),
+        Some(other) => return Err(format!("Unknown command \"{other}\"")),
+        None => return Err("No command given".to_string()),
+    }
+    let mut run_args = RunArgs::default();
+    while let Some(flag) = args.next(){
+        match flag.as_str(){
+            "--day" | "-d" => run_args.day = Some(parse_number(&flag, args.next(), |d| (1..=25).contains(&d))?),
+            "--part" | "-p" => run_args.part = Some(parse_number(&flag, args.next(), |p| p == 1 || p == 2)?),
+            "--input" | "-i" => run_args.input = Some(args.next().ok_or(format!("{flag} needs a value"))?.into()),
+            _ => return Err(format!("Unknown argument \"{flag}\"")),
+        }
+    }
+    if run_args.input.is_some() && run_args.day.is_none(){
+        return Err("--input can only be used together with --day".to_string());
+    }
+    Ok(run_args)
+}
+
+fn default_input(day: u8)->PathBuf{
+    let workspace_root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("aoc lives inside the workspace");
+    workspace_root.join(format!("d{day}")).join("Data.txt")
+}
+
+/// Runs the requested parts of a single day, printing each answer with its timing.
+/// Returns false if the input could not be read or parsed.
+fn run_day(day: u8, parts: &[u8], path: &Path)->bool{
+    let input = match read_to_string(path){
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("Day {day}: could not read {}: {e}", path.display());
+            return false;
+        }
+    };
+    for &part in parts{
+        let start = Instant::now();
+        match days::solve(day, part, &input){
+            Ok(Some(answer)) => {
+                let elapsed = start.elapsed();
+                println!("Day {day} part {part}: {answer} [{}] ({elapsed:?})", answers::check(day, part, &answer));
+            },
+            Ok(None) => println!("Day {day} part {part}: not implemented"),
+            Err(e) => {
+                eprintln!("Day {day}: {}:{e}", path.display());
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn main()->ExitCode{
+    let args = match parse_args(std::env::args().skip(1)){
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{e}\n\n{USAGE}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let parts = match args.part{
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let days = match args.day{
+        Some(day) if days::DAYS.contains(&day) => vec![day],
+        Some(day) => {
+            eprintln!("Day {day} has no solution crate.");
+            return ExitCode::FAILURE;
+        },
+        None => days::DAYS.to_vec(),
+    };
+    let start = Instant::now();
+    let mut all_ok = true;
+    for day in days{
+        let path = args.input.clone().unwrap_or_else(|| default_input(day));
+        all_ok &= run_day(day, &parts, &path);
+    }
+    println!("Total time: {:?}", start.elapsed());
+    if all_ok { ExitCode::SUCCESS } else { ExitCode::FAILURE }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    fn args(s: &str)->impl Iterator<Item = String>{
+        s.split_whitespace().map(|s| s.to_string()).collect::<Vec<String>>().into_iter()
+    }
+
+    #[test]
+    fn parses_full_run(){
+        let expected = RunArgs{day: Some(7), part: Some(2), input: Some("path.txt".into())};
+        let actual = parse_args(args("run --day 7 --part 2 --input path.txt")).unwrap();
+        assert_eq!(actual, expected)
+    }
+    #[test]
+    fn parses_run_everything(){
+        let actual = parse_args(args("run")).unwrap();
+        assert_eq!(actual, RunArgs::default())
+    }
+    #[test]
+    fn rejects_bad_arguments(){
+        assert!(parse_args(args("")).is_err());
+        assert!(parse_args(args("walk")).is_err());
+        assert!(parse_args(args("run --day 26")).is_err());
+        assert!(parse_args(args("run --part 3")).is_err());
+        assert!(parse_args(args("run --day")).is_err());
+        assert!(parse_args(args("run --input path.txt")).is_err());
+    }
+    #[test]
+    fn every_day_has_a_default_input(){
+        for day in days::DAYS{
+            assert!(default_input(day).exists(), "Missing {}", default_input(day).display());
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use std::{collections::HashMap, fs::read_to_string};
+
+use support::{input::Input, parse::{number, ParseError}, solution::Solution};
+type Lists = (Vec<i32>,Vec<i32>);
+pub fn read_file_to_sored_vec(file_name: &str)->Result<Lists,ParseError>{
+    let file = read_to_string(file_name).unwrap();
+    get_data(&file)
+}
+pub fn get_data(s: &str)->Result<Lists,ParseError>{
+    let input = Input::new(s);
+    input.lines()
+        .map(|line| {
+            let mut pair_iter = line.split_whitespace().map(|num| number::<i32>(input.text(), num));
+            match (pair_iter.next(), pair_iter.next(), pair_iter.next()){
+                (Some(a), Some(b), None) => Ok((a?,b?)),
+                _ => Err(input.error(line, "expected two numbers"))
+            }
+        }).collect()
+}
+pub fn solve1(file_name: &str)->Result<i32,ParseError>{
+    read_file_to_sored_vec(file_name).map(total_distance)
+}
+fn total_distance((mut left_vec, mut right_vec): Lists)->i32{
+    left_vec.sort();
+    right_vec.sort();
+    let solution = left_vec.iter().zip(right_vec.iter()).map(|(a,b)| {
+        let diff = (a-b).abs();
+        // println!("{a}   {b}   --> {}",diff);
+        diff
+        })
+        .sum();
+    solution
+}
+pub fn solve2(file_name: &str)->Result<i32,ParseError>{
+    read_file_to_sored_vec(file_name).map(similarity_score)
+}
+fn similarity_score((mut left_vec, right_vec): Lists)->i32{
+    left_vec.sort();
+    // right_vec.sort();
+    let mut right_count = HashMap::<i32,i32>::new();
+    right_vec.into_iter().for_each(|key|{
+        if let Some(count) = right_count.get_mut(&key){
+            *count += 1;
+        }else{
+            right_count.insert(key, 1);
+        }
+    });
+
+    left_vec.into_iter().map(|key|{
+        if let Some(&value) = right_count.get(&key){
+            key * value
+        }else{
+            0
+        }
+    }).sum()
+}
+
+pub struct Day1;
+impl Solution for Day1{
+    type Input = Lists;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        get_data(input)
+    }
+    fn part1(input: &Self::Input)->i32{
+        total_distance(input.clone())
+    }
+    fn part2(input: &Self::Input)->i32{
+        similarity_score(input.clone())
+    }
+}
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn test_data_1(){
+        let file_name = "TestData1.txt";
+        let expected = 11;
+        let actual = solve1(file_name).unwrap();
+        assert_eq!(actual,expected)
+    }
+    #[test]
+    fn test_data_2(){
+        let file_name = "TestData1.txt";
+        let expected = 31;
+        let actual = solve2(file_name).unwrap();
+        assert_eq!(actual,expected)
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = Day1::parse(&s).unwrap();
+        assert_eq!(Day1::part1(&data),11);
+        assert_eq!(Day1::part2(&data),31);
+    }
+    #[test]
+    fn windows_line_endings(){
+        let unix = get_data("3   4\n4   3\n").unwrap();
+        let windows = get_data("3   4\r\n4   3\r\n\r\n").unwrap();
+        assert_eq!(unix, windows);
+    }
+    #[test]
+    fn reports_bad_lines(){
+        let e = get_data("3   4\n4   x\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 5, "x"));
+        let e = get_data("3   4\n4\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 1, "4"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,210 @@
+use std::collections::{HashMap, HashSet};
+use map::Map;
+use support::{input::Input, search::grid_bfs, matrix::{GetSet, Matrix}, parse::ParseError, position::Position, solution::Solution};
+pub mod map{
+    use super::*;
+
+    pub type Pos = Position<usize>;
+
+    pub fn get_data(s: &str) -> Result<Map,ParseError> {
+        let input = Input::new(s).grid(|c| match c{
+            '.' => Some(None),
+            _ => c.to_digit(10).map(|i| Some(i as u8))
+        }, "a height from 0 to 9, or '.' for impassable ground")?;
+        Ok(Map::new(input))
+    }
+
+    /// A height 0 tile, with the summits it leads to and how many distinct trails lead there.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct Trailhead{
+        pub position: Pos,
+        pub summits: HashSet<Pos>,
+        pub rating: usize
+    }
+    impl Trailhead{
+        pub fn score(&self)->usize{
+            self.summits.len()
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    pub struct Map{
+        /// `None` for impassable ground.
+        heights: Matrix<Option<u8>, usize>
+    }
+    impl Map{
+        pub fn new(input: Vec<Vec<Option<u8>>>)->Self{
+            Self{heights: Matrix::new_from_square(input)}
+        }
+        pub fn get(&self, pos: Pos)->Option<u8>{
+            self.heights.get(pos).flatten()
+        }
+
+        pub fn find_starting_positions(&self)->Vec<Pos>{
+            self.heights.find_all(&Some(0)).collect()
+        }
+
+        pub fn get_filtered_neighbours(&self, pos: Pos)->Vec<Pos>{
+            if let Some(current) = self.get(pos){
+                self.heights.neighbours(pos)
+                    .into_iter()
+                    .flatten()
+                    .filter(|&n| self.get(n) == Some(current+1))
+                    .collect()
+            }else{
+                Vec::new()
+            }
+        }
+        /// How many summits (height 9) can be reached from `start` by climbing one step at a time.
+        pub fn reachable_summits(&self, start: Pos)->i32{
+            grid_bfs(&self.heights, [start], |&from, &to| from.zip(to).is_some_and(|(from, to)| to == from+1))
+                .reached()
+                .filter(|&pos| self.get(pos) == Some(9))
+                .count() as i32
+        }
+        /// Every trailhead, row by row. Works down from the summits one height at a time, so each tile is only
+        /// looked at once no matter how many trails pass through it.
+        pub fn trailheads(&self)->Vec<Trailhead>{
+            let mut reachable: HashMap<Pos, (HashSet<Pos>, usize)> = self.heights.find_all(&Some(9))
+                .map(|summit| (summit, (HashSet::from([summit]), 1)))
+                .collect();
+            for height in (0..9).rev(){
+                let level = self.heights.find_all(&Some(height)).map(|pos|{
+                    let mut summits = HashSet::new();
+                    let mut trails = 0;
+                    for next in self.get_filtered_neighbours(pos){
+                        if let Some((next_summits, next_trails)) = reachable.get(&next){
+                            summits.extend(next_summits);
+                            trails += next_trails;
+                        }
+                    }
+                    (pos, (summits, trails))
+                }).collect::<Vec<_>>();
+                reachable.extend(level);
+            }
+            self.find_starting_positions().into_iter().map(|position|{
+                let (summits, rating) = reachable.remove(&position).unwrap_or_default();
+                Trailhead{position, summits, rating}
+            }).collect()
+        }
+    }
+}
+
+pub fn solve1(data: &Map) -> i32 {
+    data.trailheads().iter().map(|trailhead| trailhead.score() as i32).sum()
+}
+pub fn solve2(data: &Map) -> i32{
+    data.trailheads().iter().map(|trailhead| trailhead.rating as i32).sum()
+}
+
+pub struct Day10;
+impl Solution for Day10{
+    type Input = Map;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        map::get_data(input)
+    }
+    fn part1(input: &Self::Input)->i32{
+        solve1(input)
+    }
+    fn part2(input: &Self::Input)->i32{
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use super::*;
+    #[test]
+    fn solve_test1_1(){
+        let file_name = "TestData1.txt";
+        let expected = 1;
+        let s = read_to_string(file_name).unwrap();
+        let data = map::get_data(&s).unwrap();
+        let solution1 = solve1(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test1_2(){
+        let file_name = "TestData2.txt";
+        let expected = 36;
+        let s = read_to_string(file_name).unwrap();
+        let data = map::get_data(&s).unwrap();
+        let solution1 = solve1(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test2_1(){
+        let file_name = "TestData1.txt";
+        let expected = 16;
+        let s = read_to_string(file_name).unwrap();
+        let data = map::get_data(&s).unwrap();
+        let solution1 = solve2(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test2_2(){
+        let file_name = "TestData2.txt";
+        let expected = 81;
+        let s = read_to_string(file_name).unwrap();
+        let data = map::get_data(&s).unwrap();
+        let solution1 = solve2(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test2_3(){
+        let file_name = "TestData3.txt";
+        let expected = 227;
+        let s = read_to_string(file_name).unwrap();
+        let data = map::get_data(&s).unwrap();
+        let solution1 = solve2(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData2.txt").unwrap();
+        let data = Day10::parse(&s).unwrap();
+        assert_eq!(Day10::part1(&data),36);
+        assert_eq!(Day10::part2(&data),81);
+    }
+    #[test]
+    fn trailheads_on_a_rectangular_map(){
+        let data = map::get_data("01234\n98765\n10001\n").unwrap();
+        let trailheads = data.trailheads();
+        assert_eq!(trailheads.len(), 4);
+        let first = &trailheads[0];
+        assert_eq!((first.position, first.score(), first.rating), (Position::new(0,0), 1, 1));
+        assert_eq!(first.summits, HashSet::from([Position::new(0,1)]));
+        assert!(trailheads[1..].iter().all(|trailhead| trailhead.score() == 0 && trailhead.rating == 0));
+    }
+    #[test]
+    fn scores_match_a_plain_search(){
+        let s = read_to_string("TestData2.txt").unwrap();
+        let data = map::get_data(&s).unwrap();
+        let scores = data.trailheads().iter().map(|trailhead| trailhead.score() as i32).collect::<Vec<_>>();
+        assert_eq!(scores, [5, 6, 5, 3, 1, 3, 5, 3, 5]);
+        for trailhead in data.trailheads(){
+            assert_eq!(trailhead.score() as i32, data.reachable_summits(trailhead.position));
+        }
+    }
+    #[test]
+    fn impassable_tiles(){
+        let fork = map::get_data("..90..9\n...1.98\n...2..7\n6543456\n765.987\n876....\n987....\n").unwrap();
+        assert_eq!(solve1(&fork), 4);
+        let two_heads = map::get_data("10..9..\n2...8..\n3...7..\n4567654\n...8..3\n...9..2\n.....01\n").unwrap();
+        assert_eq!(two_heads.trailheads().iter().map(|trailhead| trailhead.score()).collect::<Vec<_>>(), [1, 2]);
+        let rated = map::get_data(".....0.\n..4321.\n..5..2.\n..6543.\n..7..4.\n..8765.\n..9....\n").unwrap();
+        assert_eq!(solve2(&rated), 3);
+        assert_eq!(rated.get(Position::new(0,0)), None);
+    }
+    #[test]
+    fn reports_bad_maps(){
+        let e = map::get_data("0123\n12x4\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 3, "x"));
+        let e = map::get_data("0123\n123\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 1, "123"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,117 @@
+use plant::Plant;
+use support::{input::Input, matrix::Matrix, parse::ParseError, regions::{regions, Region}};
+
+pub mod plant{
+    use std::fmt::Display;
+
+    use crate::Position;
+    #[derive(Debug,Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Plant{
+        pos: Position,
+        id: char,
+    }
+    impl Plant{
+        pub fn new(pos: Position, id:char)->Self{
+            Self { pos, id }
+        }
+        pub fn pos(&self)->Position{
+            self.pos
+        }
+        pub fn id(&self)->char{
+            self.id
+        }
+    }
+    impl Display for Plant{
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let y = self.pos.y();
+            let x = self.pos.x();
+            let id = self.id();
+            write!(f,"({y},{x}) {id}")
+        }
+    }
+}
+pub type Position = support::position::Position<usize>;
+
+pub fn get_data(s:&str)->Result<Matrix<Plant, usize>,ParseError>{
+    let square = Input::new(s).grid(|c| c.is_ascii_uppercase().then_some(c), "a plant (an uppercase letter)")?;
+    let chars: Matrix<char, usize> = Matrix::new_from_square(square);
+    let width = chars.width();
+    let height = chars.height();
+    let plants_data = chars.enumerate().map(|(pos, &id)| Plant::new(pos,id)).collect::<Vec<Plant>>();
+    let plants = Matrix::new_from_flat(width, height, plants_data);
+    Ok(plants)
+}
+
+/// The fence price of every region of the same plant, with `price` giving the price of one region.
+fn total_price(data:&Matrix<Plant, usize>, price: fn(&Region<usize>)->usize)->usize{
+    regions(data, |a, b| a.id() == b.id()).iter().map(price).sum()
+}
+pub fn solve1(data:&Matrix<Plant, usize>)->usize{
+    total_price(data, |region| region.area()*region.perimeter())
+}
+pub fn solve2(data:&Matrix<Plant, usize>)->usize{
+    total_price(data, |region| region.area()*region.sides())
+}
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use super::*;
+    /// Area, perimeter and sides of the region `pos` is in.
+    fn region_at(s: &str, pos: Position)->(usize, usize, usize){
+        let data = get_data(s).unwrap();
+        let found = regions(&data, |a, b| a.id() == b.id());
+        let region = found.region_of(pos).unwrap();
+        (region.area(), region.perimeter(), region.sides())
+    }
+    #[test]
+    fn edge_test_none(){
+        assert_eq!(region_at("AAA\nAAA\nAAA", Position::new(1,1)), (9, 12, 4));
+    }
+    #[test]
+    fn edge_test_all(){
+        assert_eq!(region_at("AAA\nABA\nAAA", Position::new(1,1)), (1, 4, 4));
+        assert_eq!(region_at("AAA\nABA\nAAA", Position::new(0,0)), (8, 16, 8));
+    }
+    #[test]
+    fn edge_test_some1(){
+        assert_eq!(region_at("AAA\nABB\nABA", Position::new(1,1)), (3, 8, 6));
+    }
+    #[test]
+    fn edge_test_some2(){
+        assert_eq!(region_at("AAA\nCBB\nABA", Position::new(0,0)), (3, 8, 4));
+    }
+    #[test]
+    fn solve_test1_1(){
+        let file_name = "TestData1.txt";
+        let expected = 772;
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution1 = solve1(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test1_2(){
+        let file_name = "TestData2.txt";
+        let expected = 1930;
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution1 = solve1(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test1_3(){
+        let s = read_to_string("TestData3.txt").unwrap();
+        let data = get_data(&s).unwrap();
+        assert_eq!(solve1(&data),140)
+    }
+    #[test]
+    fn solve_test2(){
+        for (file_name, expected) in [("TestData1.txt", 436), ("TestData2.txt", 1206), ("TestData3.txt", 80)]{
+            let s = read_to_string(file_name).unwrap();
+            let data = get_data(&s).unwrap();
+            assert_eq!(solve2(&data),expected, "{file_name}")
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,251 @@
+use std::{collections::HashSet, fmt::Display};
+
+use support::{astar::{a_star, dijkstra_all}, direction::Direction, input::Input, matrix::{GetSet, Matrix}, parse::ParseError, position::Position, solution::Solution};
+type IntType = i32;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tile{
+    Wall,
+    Space,
+    Start,
+    End,
+    Visited
+}
+impl Tile{
+    pub fn new(c: char)->Option<Self>{
+        match c{
+            '#' => Some(Self::Wall),
+            '.' => Some(Self::Space),
+            'S' => Some(Self::Start),
+            'E' => Some(Self::End),
+            _ => None
+
+        }
+    }
+    pub fn is_wall(&self)->bool{
+        match self{
+            Tile::Wall => true,
+            Tile::Space => false,
+            Tile::Start => false,
+            Tile::End => false,
+            Tile::Visited => false,
+        }
+    }
+}
+impl Display for Tile{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self{
+            Tile::Wall => '#',
+            Tile::Space => ' ',
+            Tile::Start => 'S',
+            Tile::End => 'E',
+            Tile::Visited => '.',
+        };
+        write!(f,"{c}")
+    }
+}
+
+fn step_cost(from: PosRot, to: PosRot)->IntType{
+    let base_distance = ((from.pos.x() - to.pos.x()).abs() + (from.pos.y() - to.pos.y()).abs()) * MOVE_COST;
+    let mut modifier = 0;
+    if from.rot == to.rot.flip(){
+        modifier += 2;
+    }else if from.rot == to.rot.rot_left() || from.rot == to.rot.rot_right(){
+        modifier += 1;
+    }
+    base_distance + modifier*ROTATION_COST
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct PosRot{
+    pos: Position<IntType>,
+    rot: Direction
+
+}
+impl PosRot{
+    pub fn new(pos: Position<IntType>, rot: Direction)->Self{
+        Self{pos,rot}
+    }
+    pub fn neighbours(&self)->[Self;3]{
+        [   Self::new(self.pos, self.rot.rot_left()),
+            Self::new(self.pos,self.rot.rot_right()),
+            Self::new(self.pos + self.rot.as_vector(), self.rot)
+        ]
+    }
+}
+impl PartialEq<Position<IntType>> for PosRot{
+    fn eq(&self, other: &Position<IntType>) -> bool {
+        self.pos == *other
+    }
+}
+fn heuristic(pos: PosRot, goal: Position<IntType>)->IntType{
+    let base_distance = ((pos.pos.x() - goal.x()).abs() + (pos.pos.y() - goal.y()).abs()) * MOVE_COST;
+    // The headings the reindeer has to face at some point to reach the goal. Overestimating the turns
+    // would make A* miss the cheapest path, so this only counts the ones it can not do without.
+    let needed = [
+        (pos.pos.x() < goal.x(), Direction::Right),
+        (pos.pos.x() > goal.x(), Direction::Left),
+        (pos.pos.y() < goal.y(), Direction::Down),
+        (pos.pos.y() > goal.y(), Direction::Up),
+    ].into_iter().filter(|(is_needed,_)| *is_needed).map(|(_,direction)| direction).collect::<Vec<Direction>>();
+    let turns = match needed[..]{
+        [] => 0,
+        [heading] if heading == pos.rot => 0,
+        [heading] if heading == pos.rot.flip() => 2,
+        [_] => 1,
+        _ if needed.contains(&pos.rot) => 1,
+        _ => 2
+    };
+    base_distance + turns*ROTATION_COST
+
+}
+
+pub fn get_data(s: &str) -> Result<Matrix<Tile>,ParseError> {
+    let data = Input::new(s).grid(Tile::new, "'#', '.', 'S' or 'E'")?;
+    for (tile, name) in [(Tile::Start, "start ('S')"), (Tile::End, "end ('E')")]{
+        if !data.iter().flatten().any(|t| *t == tile){
+            return Err(ParseError::whole_input(format!("the maze has no {name}")));
+        }
+    }
+    Ok(Matrix::new_from_square(data))
+}
+
+pub fn solve1(data: &Matrix<Tile>) -> IntType {
+    let start = PosRot::new(data.find(&Tile::Start).expect("We know this exists in our data-set"), Direction::Right);
+    let goal = data.find(&Tile::End).expect("We know this exists in our data-set");
+    let is_open = |pos: Position<IntType>| data.get(pos).is_some_and(|tile| !tile.is_wall());
+    let (_, total_cost) = a_star(start,
+        |current| current.pos == goal,
+        |current| current.neighbours().into_iter().filter(|n| is_open(n.pos)),
+        |from, to| step_cost(*from, *to),
+        |current| heuristic(*current, goal))
+        .expect("There is at least one valid path from start to goal.");
+    total_cost
+}
+
+/// Every tile on at least one of the cheapest paths from start to end.
+pub fn best_path_tiles(data: &Matrix<Tile>) -> HashSet<Position<IntType>> {
+    let start = PosRot::new(data.find(&Tile::Start).expect("We know this exists in our data-set"), Direction::Right);
+    let goal = data.find(&Tile::End).expect("We know this exists in our data-set");
+    let is_open = |pos: Position<IntType>| data.get(pos).is_some_and(|tile| !tile.is_wall());
+    let best = dijkstra_all(start,
+        |current| current.pos == goal,
+        |current| current.neighbours().into_iter().filter(|n| is_open(n.pos)),
+        |from, to| step_cost(*from, *to))
+        .expect("There is at least one valid path from start to goal.");
+    best.states().into_iter().map(|state| state.pos).collect()
+}
+
+/// The maze with every tile in `tiles` marked as visited, ready to print.
+pub fn mark_tiles(data: &Matrix<Tile>, tiles: &HashSet<Position<IntType>>) -> Matrix<Tile> {
+    let mut marked = data.clone();
+    tiles.iter().for_each(|p| marked.set(*p, Tile::Visited));
+    marked
+}
+
+pub fn solve2(data: &Matrix<Tile>) -> IntType {
+    best_path_tiles(data).len() as IntType
+}
+
+const ROTATION_COST: IntType = 1000;
+const MOVE_COST: IntType = 1;
+
+pub struct Day16;
+impl Solution for Day16{
+    type Input = Matrix<Tile>;
+    type Answer1 = IntType;
+    type Answer2 = IntType;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        get_data(input)
+    }
+    fn part1(input: &Self::Input)->IntType{
+        solve1(input)
+    }
+    fn part2(input: &Self::Input)->IntType{
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use support::solution::Solution;
+
+    use crate::{best_path_tiles,get_data,mark_tiles,solve1,solve2,Day16,Tile};
+
+    #[test]
+    fn solve_test1_1(){
+        let expected = 7036;
+        let file_name = "TestData1.txt";
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution = solve1(&data);
+        assert_eq!(solution,expected)
+    }
+
+    #[test]
+    fn solve_test1_2(){
+        let expected = 11048;
+        let file_name = "TestData2.txt";
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution = solve1(&data);
+        assert_eq!(solution,expected)
+    }
+    #[test]
+    fn solve_test2_1(){
+        let expected = 45;
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = get_data(&s).unwrap();
+        assert_eq!(solve2(&data),expected)
+    }
+
+    #[test]
+    fn solve_test2_2(){
+        let expected = 64;
+        let s = read_to_string("TestData2.txt").unwrap();
+        let data = get_data(&s).unwrap();
+        assert_eq!(solve2(&data),expected)
+    }
+    #[test]
+    fn marks_best_path_tiles(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = get_data(&s).unwrap();
+        let tiles = best_path_tiles(&data);
+        let marked = mark_tiles(&data, &tiles);
+        assert_eq!(marked.enumerate().filter(|(_, tile)| **tile == Tile::Visited).count(), 45);
+        assert!(tiles.iter().all(|&pos| marked.tile(pos) == Some(&Tile::Visited)));
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData2.txt").unwrap();
+        let data = Day16::parse(&s).unwrap();
+        assert_eq!(Day16::part1(&data),11048);
+    }
+    #[test]
+    fn solve_test1_minimal(){
+        let expected = 1;
+        let file_name = "TestMinimal.txt";
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution = solve1(&data);
+        assert_eq!(solution,expected)
+    }
+    #[test]
+    fn solve_test1_small(){
+        let expected = 3004;
+        let file_name = "TestSmall.txt";
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution = solve1(&data);
+        assert_eq!(solution,expected)
+    }
+    #[test]
+    fn reports_bad_mazes(){
+        let e = get_data("####\n#SE#\n#.x#\n####").err().unwrap();
+        assert_eq!((e.line(), e.column(), e.text()), (3, 3, "x"));
+        let e = get_data("####\n#S.#\n####").err().unwrap();
+        assert_eq!(e.line(), 0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,247 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use support::{input::Input, parse::{fields, ParseError}, solution::Solution};
+
+type Data<'a> = (Vec<&'a str>,Vec<&'a str>);
+
+pub fn get_data(s:&str)->Result<Data<'_>,ParseError>{
+    let input = Input::new(s);
+    let mut blocks = input.blocks();
+    let (Some(patterns), Some(designs), None) = (blocks.next(), blocks.next(), blocks.next()) else {
+        return Err(ParseError::whole_input("expected a block of towel patterns and a block of designs"));
+    };
+    let segments = patterns.into_iter().flat_map(|line| fields(line, ',')).map(|seg| stripes(input.text(), seg)).collect::<Result<Vec<&str>,ParseError>>()?;
+    let targets = designs.into_iter().map(|line| stripes(input.text(), line)).collect::<Result<Vec<&str>,ParseError>>()?;
+    Ok((segments,targets))
+}
+fn stripes<'a>(source: &str, pattern: &'a str)->Result<&'a str,ParseError>{
+    match pattern.char_indices().find(|&(_,c)| !"wubrg".contains(c)){
+        Some((idx,c)) => Err(ParseError::at(source, &pattern[idx..idx+c.len_utf8()], "expected a stripe colour (w, u, b, r or g)")),
+        None => Ok(pattern)
+    }
+}
+fn filter_criteria1(target: &str, segments: &Vec<&str>)->bool{
+    if target.is_empty(){
+        return true;
+    }
+    for &segment in segments.iter(){
+        if target == segment{
+            return true;
+        }
+        if let Some(new_target) = target.strip_prefix(segment) {
+            // println!("'{target}' started with '{segment}'");
+            // let mut new_segments = segments.clone();
+            // new_segments.remove(idx);
+            // println!("Does '{new_target}' start with any of {new_segments:?}?");
+            if filter_criteria1(new_target, segments){
+                return true;
+            }
+        }
+        else{
+            // println!();
+            // println!("'{target}' DID NOT start with  '{segment}'");   
+        }
+    }
+    // println!("{target} did not start with any of {segments:?}");
+    false
+}
+pub fn solve1(data: &Data)->usize{
+    let (segments, targets) = data;
+    targets.iter().filter(|&&target| {
+        if filter_criteria1(target, segments){
+            // #[cfg(debug_assertions)]
+            // println!("target: {target:?} is valid from {segments:?}");
+            true
+        }else{
+            // #[cfg(debug_assertions)]
+            // println!("target: {target:?} is INVALID from {segments:?}");
+            false
+        }
+    }).count()
+}
+
+/// The number of ways `target` can be made from the segments. Suffixes that were already counted are looked up in `cache`.
+fn filter_criteria2<'a>(target: &'a str, segment_map: &HashMap<char,Vec<&str>>, cache: &mut HashMap<&'a str,usize>)->usize{
+    if target.is_empty(){
+        return 1;
+    }
+    if let Some(&known) = cache.get(target){
+        return known;
+    }
+    let mut pot = 0;
+    if let Some(segments) = segment_map.get(&target.chars().next().unwrap()){
+        for &segment in segments.iter(){
+            if target == segment{
+                pot += 1;
+                continue;
+            }
+            if let Some(new_target) = target.strip_prefix(segment) {
+                pot += filter_criteria2(new_target, segment_map, cache);
+            }
+        }
+    }
+    cache.insert(target, pot);
+    pot
+}
+fn compare_string_length(a: &str, b:&str)->Ordering{
+    if a.len() == b.len(){
+        Ordering::Equal
+    }else if a.len() < b.len(){
+        Ordering::Less
+    }else{
+        Ordering::Greater
+    }
+}
+fn order_segments<'a>(segments:&'a [&str])->Vec<&'a str>{
+    let mut ordered_segments = segments.to_vec();
+    ordered_segments.sort_by(|a,b| compare_string_length(a,b));
+    ordered_segments
+}
+pub fn solve2<'a>(data: &'a Data)->usize{
+    let (segments, targets) = data;
+    let ordered_segments = order_segments(segments);
+    // println!("{ordered_segments:?}");
+    let cleaned_segments = ordered_segments.clone()
+        .into_iter()
+        .enumerate()
+        .filter(|&(idx, target)| !filter_criteria1(target, &ordered_segments[(idx+1)..].to_vec()) )
+        .map(|(_,s)| s).collect::<Vec<&str>>();
+
+    // println!("{cleaned_segments:?}\n");
+    let segment_map = cleaned_segments.into_iter()
+        .map(|seg| (seg.chars().next().unwrap(),seg))
+        .fold(HashMap::<char,Vec<&'a str>>::new(), | mut acc,(key,val)|{
+            if let Some(vec) = acc.get_mut(&key){
+                vec.push(val);
+            }else{
+                acc.insert(key, vec![val]);
+            }
+            acc
+        } );
+        
+    let mut cache = HashMap::new();
+    targets.iter().map(|&target| {
+        let count = filter_criteria2(target, &segment_map, &mut cache);
+        if count > 0{
+            // #[cfg(debug_assertions)]
+            // println!("{target:?} <-- {segment_map:?}");
+        }
+        count
+    }).sum::<usize>()
+}
+
+/// Owned version of [`Data`], so the parsed input does not borrow from the text it came from.
+type OwnedData = (Vec<String>,Vec<String>);
+fn borrow_data((segments, targets): &OwnedData)->Data<'_>{
+    (segments.iter().map(String::as_str).collect(), targets.iter().map(String::as_str).collect())
+}
+pub struct Day19;
+impl Solution for Day19{
+    type Input = OwnedData;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        let (segments, targets) = get_data(input)?;
+        Ok((segments.into_iter().map(str::to_string).collect(), targets.into_iter().map(str::to_string).collect()))
+    }
+    fn part1(input: &Self::Input)->usize{
+        solve1(&borrow_data(input))
+    }
+    fn part2(input: &Self::Input)->usize{
+        solve2(&borrow_data(input))
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    mod s1{
+        use super::*;
+        #[test]
+        fn getting_expected_data(){
+            let expected_segments = vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"];
+            let expected_targets = vec![ "brwrr", "bggr", "gbbr", "rrbgbr", "ubwu", "bwurrg", "brgr", "bbrgwb"];
+
+            let file_name = "TestData1.txt";
+            let s = read_to_string(file_name).unwrap();
+            let data = get_data(&s).unwrap();
+            assert_eq!(data.0,expected_segments);
+            assert_eq!(data.1,expected_targets);
+        }
+        #[test]
+        fn solve1_1(){
+            let expected = 6;
+            let file_name = "TestData1.txt";
+            let s = read_to_string(file_name).unwrap();
+            let data = get_data(&s).unwrap();
+            let solution = solve1(&data);
+            assert_eq!(solution,expected)
+        }
+        #[test]
+        fn solve1_2(){
+            let expected = 1;
+            let s = "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr".to_string();
+            let data = get_data(&s).unwrap();
+            let solution = solve1(&data);
+            assert_eq!(solution,expected)
+        }
+        #[test]
+        fn starts_with_test1(){
+            let s = "brwrr";
+            let subs = &s[1..];
+            assert!(subs.starts_with('r'));
+        }
+        #[test]
+        fn starts_with_test2(){
+            let s = "brwrr";
+            let subs = &s[3..];
+            assert!(subs.starts_with('r'));
+        }
+    }
+    mod s2{
+        use super::*;
+        #[test]
+        fn solve2_1(){
+            let expected = 16;
+            let file_name = "TestData1.txt";
+            let s = read_to_string(file_name).unwrap();
+            let data = get_data(&s).unwrap();
+            let solution = solve2(&data);
+            assert_eq!(solution,expected)
+        }
+        #[test]
+        fn solve2_2(){
+            let expected = 2;
+            let s = "r, wr, b, g, bwu, rb, gb, br\n\nbrwrr".to_string();
+            let data = get_data(&s).unwrap();
+            let solution = solve2(&data);
+            assert_eq!(solution,expected)
+        }
+        #[test]
+        fn solve2_3(){
+            // let expected = 16;
+            let file_name = "TestData2.txt";
+            let s = read_to_string(file_name).unwrap();
+            let data = get_data(&s).unwrap();
+            let solution = solve2(&data);
+            assert!(solution != 0)
+        }
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = Day19::parse(&s).unwrap();
+        assert_eq!(Day19::part1(&data),6);
+        assert_eq!(Day19::part2(&data),16);
+    }
+    #[test]
+    fn reports_bad_stripes(){
+        let e = get_data("r, wr, b\n\nbrwrr\nbxw").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (4, 2, "x"));
+        let e = get_data("r, wx, b\n\nbrwrr").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (1, 5, "x"));
+    }
+}
\ No newline at end of file
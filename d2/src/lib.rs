@@ -0,0 +1,284 @@
+use std::fs::read_to_string;
+
+use support::{input::Input, parse::{number, ParseError}, solution::Solution};
+
+pub fn get_numbers_from_file(file_name: &str)->Result<Vec<Vec<i32>>,ParseError>{
+    get_data(&read_to_string(file_name).unwrap())
+}
+pub fn get_data(s: &str)->Result<Vec<Vec<i32>>,ParseError>{
+    let input = Input::new(s);
+    input.lines()
+        .map(|line| numbers_in(input.text(), line))
+        .collect()
+}
+pub fn get_numbers_from_line(line: &str)->Result<Vec<i32>,ParseError>{
+    numbers_in(line, line)
+}
+fn numbers_in(source: &str, line: &str)->Result<Vec<i32>,ParseError>{
+    line
+        .split(' ')
+        .map(|n| number::<i32>(source, n))
+        .collect()
+}
+
+/// How safe a report is.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Safety{
+    Safe,
+    /// Safe once the levels at these indices are removed.
+    SafeWithDampener(Vec<usize>),
+    Unsafe
+}
+impl Safety{
+    pub fn is_safe(&self)->bool{
+        match self{
+            Self::Safe | Self::SafeWithDampener(_) => true,
+            Self::Unsafe => false,
+        }
+    }
+}
+
+/// One line of the input: a sequence of levels.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Report{
+    levels: Vec<i32>
+}
+impl Report{
+    pub fn new(levels: Vec<i32>)->Self{
+        Self{levels}
+    }
+    pub fn levels(&self)->&[i32]{
+        &self.levels
+    }
+    /// Safe means the levels only go up or only go down, by 1 to 3 at a time.
+    /// The dampener may remove up to `tolerance` levels to get there; it removes as few as it can.
+    pub fn classify(&self, tolerance: usize)->Safety{
+        let removed = [1, -1].into_iter()
+            .filter_map(|direction| self.removals(direction, tolerance))
+            .min_by_key(Vec::len);
+        match removed{
+            None => Safety::Unsafe,
+            Some(removed) if removed.is_empty() => Safety::Safe,
+            Some(removed) => Safety::SafeWithDampener(removed)
+        }
+    }
+    /// The fewest levels to remove so every step goes in `direction` by 1 to 3, if that is at most `tolerance`.
+    /// A level can only follow one of the `tolerance + 1` levels before it, so this is linear in the report length.
+    fn removals(&self, direction: i32, tolerance: usize)->Option<Vec<usize>>{
+        let len = self.levels.len();
+        if len == 0{
+            return Some(Vec::new());
+        }
+        // For every level: the fewest removals before it if it is kept, and the kept level before it.
+        let mut best: Vec<Option<(usize, Option<usize>)>> = Vec::with_capacity(len);
+        for (i, &level) in self.levels.iter().enumerate(){
+            let mut current = (i <= tolerance).then_some((i, None));
+            for (j, previous) in best.iter().enumerate().skip(i.saturating_sub(tolerance + 1)){
+                let Some((removed, _)) = previous else{
+                    continue;
+                };
+                let removed = removed + i - j - 1;
+                let step = (level - self.levels[j]) * direction;
+                if (1..=3).contains(&step) && removed <= tolerance && current.is_none_or(|(fewest, _)| removed < fewest){
+                    current = Some((removed, Some(j)));
+                }
+            }
+            best.push(current);
+        }
+        let (mut last, _) = best.iter().enumerate()
+            .filter_map(|(i, found)| Some((i, found.as_ref()?.0 + len - 1 - i)))
+            .filter(|&(_, removed)| removed <= tolerance)
+            .min_by_key(|&(_, removed)| removed)?;
+        let mut kept = vec![false; len];
+        kept[last] = true;
+        while let Some((_, Some(previous))) = best[last]{
+            kept[previous] = true;
+            last = previous;
+        }
+        Some((0..len).filter(|&i| !kept[i]).collect())
+    }
+}
+
+pub fn get_reports(s: &str)->Result<Vec<Report>,ParseError>{
+    Ok(get_data(s)?.into_iter().map(Report::new).collect())
+}
+
+pub fn is_line_valid(line: &[i32])->bool{
+    Report::new(line.to_vec()).classify(1).is_safe()
+}
+
+/// How many reports are safe when the dampener may remove up to `tolerance` levels.
+pub fn count_safe(reports: &[Report], tolerance: usize)->usize{
+    reports.iter().filter(|report| report.classify(tolerance).is_safe()).count()
+}
+
+pub struct Day2;
+impl Solution for Day2{
+    type Input = Vec<Report>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        get_reports(input)
+    }
+    fn part1(input: &Self::Input)->usize{
+        count_safe(input, 0)
+    }
+    fn part2(input: &Self::Input)->usize{
+        count_safe(input, 1)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn solves_test_case1(){
+        let reports = get_reports(&read_to_string("TestData.txt").unwrap()).unwrap();
+        assert_eq!(count_safe(&reports, 0),2);
+    }
+    #[test]
+    fn through_solution_trait(){
+        let data = Day2::parse(&read_to_string("Data.txt").unwrap()).unwrap();
+        assert_eq!(Day2::part1(&data),472);
+        assert_eq!(Day2::part2(&data),520);
+    }
+    mod case_2{
+        use super::*;
+
+        #[test]
+        fn mostly_valid_reports(){
+            let reports = [
+                "-5 2 3 4 5",
+                "0 2 3 4 5",
+                "1 2 3 4 5",
+                "2 2 3 4 5",
+                "3 2 3 4 5",
+                "9 2 3 4 5",
+
+                "1 -2 3 4 5",
+                "1 0 3 4 5",
+                "1 2 3 4 5",
+                "1 3 3 4 5",
+                "1 4 3 4 5",
+                "1 10 3 4 5",
+
+                "1 2 -1 4 5",
+                "1 2 0 4 5",
+                "1 2 3 4 5",
+                "1 2 4 4 5",
+                "1 2 5 4 5",
+                "1 2 10 4 5",
+
+                "1 2 3 1 5",
+                "1 2 3 2 5",
+                "1 2 3 3 5",
+                "1 2 3 4 5",
+                "1 2 3 5 5",
+                "1 2 3 10 5",
+
+                "1 2 3 4 0",
+                "1 2 3 4 3",
+                "1 2 3 4 4",
+                "1 2 3 4 5",
+                "1 2 3 4 6",
+                "1 2 3 4 10",
+            ];
+            for s in reports{
+                let nums = get_numbers_from_line(s).unwrap();
+                let line_validity = is_line_valid(&nums);
+                assert!(line_validity, "Line: {nums:?} should be valid, but is invalid.");
+            }
+        }
+        #[test]
+        fn invalid_reports(){
+            let reports = [
+                "1 2 2 2 5",
+                "1 2 6 7 9",
+                "3 2 1 2 3",
+            ];
+            for s in reports{
+                let nums = get_numbers_from_line(s).unwrap();
+                let line_validity = is_line_valid(&nums);
+                assert!( !line_validity, "Line: {nums:?} should be invalid, but is valid.");
+            }
+        }
+    
+        #[test]
+        fn solves_test_case2(){
+            let expected = 4;
+            let file_name = "TestData.txt";
+            let reports = get_reports(&read_to_string(file_name).unwrap()).unwrap();
+            assert_eq!(count_safe(&reports, 1),expected)
+        }
+        #[test]
+        fn reports_what_was_removed(){
+            let classify = |s: &str, tolerance: usize| Report::new(get_numbers_from_line(s).unwrap()).classify(tolerance);
+            assert_eq!(classify("7 6 4 2 1", 1), Safety::Safe);
+            assert_eq!(classify("1 3 2 4 5", 0), Safety::Unsafe);
+            assert_eq!(classify("1 2 7 3 4", 1), Safety::SafeWithDampener(vec![2]));
+            assert_eq!(classify("1 2 3 4 10", 1), Safety::SafeWithDampener(vec![4]));
+            assert_eq!(classify("9 1 2 3 4", 1), Safety::SafeWithDampener(vec![0]));
+            assert_eq!(classify("1 2 9 9 3 4", 1), Safety::Unsafe);
+            assert_eq!(classify("1 2 9 9 3 4", 2), Safety::SafeWithDampener(vec![2, 3]));
+            assert_eq!(classify("5 5", 0), Safety::Unsafe);
+            assert_eq!(classify("5 5", 1), Safety::SafeWithDampener(vec![1]));
+        }
+        #[test]
+        fn short_reports(){
+            let classify = |levels: &[i32], tolerance: usize| Report::new(levels.to_vec()).classify(tolerance);
+            assert_eq!(classify(&[], 1), Safety::Safe);
+            assert_eq!(classify(&[4], 0), Safety::Safe);
+            assert_eq!(classify(&[1, 2], 1), Safety::Safe);
+            assert_eq!(classify(&[1, 2, 3], 2), Safety::Safe);
+            assert_eq!(classify(&[3, 2, 1], 2), Safety::Safe);
+            assert_eq!(classify(&[1, 9], 1), Safety::SafeWithDampener(vec![1]));
+            assert_eq!(classify(&[7, 5, 5], 2), Safety::SafeWithDampener(vec![2]));
+            assert_eq!(classify(&[1, 2, 2], 2), Safety::SafeWithDampener(vec![2]));
+            assert_eq!(classify(&[5, 5, 5], 2), Safety::SafeWithDampener(vec![1, 2]));
+        }
+        #[test]
+        fn removes_as_few_levels_as_possible(){
+            let is_safe = |levels: &[i32]| [1, -1].iter().any(|direction| levels.windows(2).all(|pair| (1..=3).contains(&((pair[1] - pair[0]) * direction))));
+            // The fewest removals that make `levels` safe, trying every subset.
+            let brute_force = |levels: &[i32]| (0..1_usize << levels.len())
+                .filter(|removed| is_safe(&levels.iter().enumerate().filter(|&(i, _)| removed & (1 << i) == 0).map(|(_, &l)| l).collect::<Vec<i32>>()))
+                .map(|removed| removed.count_ones() as usize)
+                .min()
+                .unwrap();
+            for tolerance in 0..=3{
+                for len in 0..=tolerance + 2{
+                    // Every report of this length with levels 0 to 4.
+                    for code in 0..5_usize.pow(len as u32){
+                        let levels = (0..len).map(|i| (code / 5_usize.pow(i as u32) % 5) as i32).collect::<Vec<i32>>();
+                        let fewest = brute_force(&levels);
+                        let removed = match Report::new(levels.clone()).classify(tolerance){
+                            Safety::Safe => Some(Vec::new()),
+                            Safety::SafeWithDampener(removed) => Some(removed),
+                            Safety::Unsafe => None
+                        };
+                        match removed{
+                            Some(removed) => {
+                                assert_eq!(removed.len(), fewest, "{levels:?} with tolerance {tolerance}");
+                                let kept = levels.iter().enumerate().filter(|(i, _)| !removed.contains(i)).map(|(_, &l)| l).collect::<Vec<i32>>();
+                                assert!(is_safe(&kept), "{levels:?} with tolerance {tolerance}");
+                            },
+                            None => assert!(fewest > tolerance, "{levels:?} with tolerance {tolerance}")
+                        }
+                    }
+                }
+            }
+        }
+        #[test]
+        fn matches_removing_every_level_in_turn(){
+            // The brute force from the first attempt at part 2.
+            let brute_force = |levels: &[i32]| (0..levels.len()).any(|skip|{
+                let rest = levels.iter().enumerate().filter(|&(i,_)| i != skip).map(|(_,&l)| l).collect::<Vec<i32>>();
+                Report::new(rest).classify(0).is_safe()
+            });
+            let reports = get_reports(&read_to_string("Data.txt").unwrap()).unwrap();
+            for report in reports{
+                assert_eq!(report.classify(1).is_safe(), brute_force(report.levels()), "{:?}", report.levels());
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+pub mod position;
+pub mod direction;
+pub mod tile;
+pub mod track;
+use std::collections::BTreeMap;
+use position::Position;
+use support::{search::grid_bfs, matrix::{GetSet, Matrix}, parse::ParseError, solution::Solution};
+use tile::Tile;
+use track::Track;
+
+pub type IntType = i32;
+
+/// How far every tile of the race path is from `pos`.
+fn distances_from(track:&Track, pos: Position<IntType>)->Matrix<Option<i32>>{
+    grid_bfs(track.tiles(), [pos], |_, &to| to != Tile::Wall)
+        .distances()
+        .map(|distance| distance.map(|d| d as i32))
+}
+
+/// How far every tile of the race path is from the end.
+pub fn get_distance_matrix(tile_matrix:&Track)->Matrix<Option<i32>>{
+    distances_from(tile_matrix, tile_matrix.get_end())
+}
+
+/// How many cheats save how many picoseconds. A cheat goes from one track tile to another, ignoring walls,
+/// in at most `max_cheat_length` steps. Cheats that do not save anything are left out.
+pub fn cheat_savings(tile_matrix:&Track, max_cheat_length:i32)->BTreeMap<i32,i32>{
+    let from_start = distances_from(tile_matrix, tile_matrix.get_start());
+    let to_end = get_distance_matrix(tile_matrix);
+    let mut savings = BTreeMap::new();
+    let Some(Some(race_length)) = to_end.get(tile_matrix.get_start()) else{
+        return savings;
+    };
+    for (cheat_start, before) in from_start.enumerate().filter_map(|(pos, d)| Some((pos, (*d)?))){
+        for dy in -max_cheat_length..=max_cheat_length{
+            let max_dx = max_cheat_length - dy.abs();
+            for dx in -max_dx..=max_dx{
+                let Some(Some(after)) = to_end.get(cheat_start + Position::new(dx, dy)) else{
+                    continue;
+                };
+                let saved = race_length - (before + dx.abs() + dy.abs() + after);
+                if saved > 0{
+                    *savings.entry(saved).or_insert(0) += 1;
+                }
+            }
+        }
+    }
+    savings
+}
+
+/// The number of cheats of at most `max_shortcut_length` steps that save at least `savings_threshold` picoseconds.
+pub fn get_shortcuts(tile_matrix:&Track,savings_threshold:i32, max_shortcut_length:i32)->i32{
+    cheat_savings(tile_matrix, max_shortcut_length).range(savings_threshold.max(1)..).map(|(_, count)| count).sum()
+}
+
+pub struct Day20;
+impl Solution for Day20{
+    type Input = Track;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        Track::try_from(input)
+    }
+    fn part1(input: &Self::Input)->i32{
+        get_shortcuts(input, 100, 2)
+    }
+    fn part2(input: &Self::Input)->i32{
+        get_shortcuts(input, 100, 20)
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    use test_case::test_case;
+    #[test_case("TestData.txt",1, 44)]
+    #[test_case("TestData.txt",20, 5)]
+    #[test_case("Data.txt",100, 1375)]
+    fn solves_p1(path:&str, shortcut_value_threshold: i32, expected:i32){
+        let tile_matrix = Track::new(path).unwrap();
+        let shortcut_count = get_shortcuts(&tile_matrix,shortcut_value_threshold, 2);
+        assert_eq!(shortcut_count,expected)
+    }
+    #[test_case("TestData.txt",50, 285)]
+    #[test_case("TestData.txt",76, 3)]
+    fn solves_p2(path:&str, shortcut_value_threshold: i32, expected:i32){
+        let tile_matrix = Track::new(path).unwrap();
+        let shortcut_count = get_shortcuts(&tile_matrix,shortcut_value_threshold, 20);
+        assert_eq!(shortcut_count,expected)
+    }
+    #[test]
+    fn savings_histogram(){
+        let tile_matrix = Track::new("TestData.txt").unwrap();
+        let expected = BTreeMap::from([(2,14),(4,14),(6,2),(8,4),(10,2),(12,3),(20,1),(36,1),(38,1),(40,1),(64,1)]);
+        assert_eq!(cheat_savings(&tile_matrix, 2), expected);
+        let long_cheats = cheat_savings(&tile_matrix, 20);
+        assert_eq!((long_cheats.get(&50), long_cheats.get(&76)), (Some(&32), Some(&3)));
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = std::fs::read_to_string("Data.txt").unwrap();
+        let data = Day20::parse(&s).unwrap();
+        assert_eq!(Day20::part1(&data),1375);
+    }
+    #[test]
+    fn reports_bad_tracks(){
+        let e = Track::try_from("#####\n#S.E#\n#.o.#\n#####").err().unwrap();
+        assert_eq!((e.line(), e.column(), e.text()), (3, 3, "o"));
+        let e = Track::try_from("#####\n#S..#\n#####").err().unwrap();
+        assert_eq!(e.line(), 0);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use std::fs::read_to_string;
+
+use support::{input::Input, matrix::Matrix, parse::ParseError};
+
+use crate::{position::Position, tile::Tile, IntType};
+
+/// The race track, with where the race starts and where it ends.
+#[derive(Clone)]
+pub struct Track{
+    tiles: Matrix<Tile>,
+    goal: Position<IntType>,
+    start: Position<IntType>
+}
+impl Track{
+    pub fn new(file_path: &str)->Result<Self,ParseError>{
+        let s = read_to_string(file_path).unwrap();
+        Self::try_from(s.as_str())
+    }
+    pub fn tiles(&self)->&Matrix<Tile>{
+        &self.tiles
+    }
+    pub fn get_end(&self)->Position<IntType>{
+        self.goal
+    }
+    pub fn get_start(&self)->Position<IntType>{
+        self.start
+    }
+}
+impl TryFrom<&str> for Track{
+    type Error = ParseError;
+    fn try_from(value: &str) -> Result<Self,ParseError> {
+        let tiles = Input::new(value).grid(|c| {
+            match c{
+                '#' => Some(Tile::Wall),
+                '.' => Some(Tile::Space),
+                'S' => Some(Tile::Start),
+                'E' => Some(Tile::End),
+                _ => None
+            }
+        }, "'#', '.', 'S' or 'E'")?;
+        let tiles = Matrix::new_from_square(tiles);
+        let goal = tiles.find(&Tile::End).ok_or_else(|| ParseError::whole_input("the track has no end ('E')"))?;
+        let start = tiles.find(&Tile::Start).ok_or_else(|| ParseError::whole_input("the track has no start ('S')"))?;
+        Ok(Self { tiles, goal, start })
+    }
+}
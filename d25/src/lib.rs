@@ -0,0 +1,133 @@
+use support::{input::Input, parse::{grid, ParseError}, solution::{Solution, Unsolved}};
+
+pub type Data = (Vec<Key>, Vec<Lock>);
+#[derive(Debug,PartialEq, Eq, PartialOrd, Ord,Hash,Clone, Copy)]
+pub struct Key{
+    data: [i32;5]
+}
+impl Key{
+    pub fn new(source: &str, segments: Vec<&str>)->Result<Self,ParseError>{
+        Ok(Self{data: column_heights(source, &segments)?})
+    }
+}
+#[derive(Debug,PartialEq, Eq, PartialOrd, Ord,Hash,Clone, Copy)]
+pub struct Lock{
+    data: [i32;5]
+}
+impl Lock{
+    pub fn new(source: &str, segments: Vec<&str>)->Result<Self,ParseError>{
+        Ok(Self{data: column_heights(source, &segments)?})
+    }
+}
+/// Counts the '#' in each of the five columns of a 7 row schematic.
+fn column_heights(source: &str, segments: &[&str])->Result<[i32;5],ParseError>{
+    if segments.len() != 7{
+        return Err(ParseError::at(source, segments[0], format!("expected a schematic of 7 rows, found {} rows", segments.len())));
+    }
+    let rows = grid(source, segments, |c| match c{
+        '#' => Some(1),
+        '.' => Some(0),
+        _ => None
+    }, "'#' or '.'")?;
+    if rows[0].len() != 5{
+        return Err(ParseError::at(source, segments[0], "expected a row of 5 pins"));
+    }
+    Ok(std::array::from_fn(|col| rows.iter().map(|row| row[col]).sum()))
+}
+#[derive(Debug,PartialEq, Eq, PartialOrd, Ord,Hash,Clone, Copy)]
+enum KeyOrLock{
+    Key(Key),
+    Lock(Lock)
+}
+
+fn key_fits_in_lock(key: &Key, lock: &Lock)->bool{
+    lock.data.iter().zip(key.data.iter()).all(|(l,k)| k+l <= 7)
+}
+
+
+pub fn get_data(s: &str) -> Result<Data,ParseError>{
+    let input = Input::new(s);
+    let points = input.blocks().map(|segment|{
+        if segment[0].starts_with("#####"){
+            Ok(KeyOrLock::Lock(Lock::new(input.text(), segment)?))
+        }else{
+            Ok(KeyOrLock::Key(Key::new(input.text(), segment)?))
+        }
+    });
+    let mut data = (Vec::<Key>::new(),Vec::<Lock>::new());
+    for val in points{
+        match val?{
+            KeyOrLock::Key(key) => data.0.push(key),
+            KeyOrLock::Lock(lock) => data.1.push(lock),
+        }
+    }
+    Ok(data)
+}
+
+pub fn solve1(data: &Data) -> i32 {
+    let (keys, locks) = data;
+    let mut count = 0;
+    // println!("key count: {}, locks count: {}", keys.len(), locks.len());
+    for key in keys{
+        for lock in locks{
+            if key_fits_in_lock(key,lock){
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+pub struct Day25;
+impl Solution for Day25{
+    type Input = Data;
+    type Answer1 = i32;
+    type Answer2 = Unsolved;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        get_data(input)
+    }
+    fn part1(input: &Self::Input)->i32{
+        solve1(input)
+    }
+    fn part2(_input: &Self::Input)->Unsolved{
+        Unsolved
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use super::*;
+
+    #[test]
+    fn test(){
+        // let file_name = "Data.txt";
+        let file_name = "TestData1.txt";
+        let expected = 3;
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution1 = solve1(&data);
+        assert_eq!(solution1, expected)
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = Day25::parse(&s).unwrap();
+        assert_eq!(Day25::part1(&data), 3);
+    }
+    #[test]
+    fn windows_line_endings(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let windows = s.replace('\n', "\r\n");
+        assert_eq!(get_data(&s).unwrap(), get_data(&windows).unwrap());
+    }
+    #[test]
+    fn reports_bad_schematics(){
+        let e = get_data("#####\n.####\n.####\n.####\n.#.#.\n.#...\n.....\n\n#####\n##.##\n").unwrap_err();
+        assert_eq!((e.line(), e.column()), (9, 1));
+        let e = get_data("#####\n.####\n.####\n.##o#\n.#.#.\n.#...\n.....\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (4, 4, "o"));
+    }
+}
\ No newline at end of file
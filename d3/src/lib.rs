@@ -0,0 +1,251 @@
+use support::{input::Input, parse::ParseError, solution::Solution};
+
+/// What running an instruction does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Effect{
+    /// Adds to the sum, but only while instructions are enabled.
+    Add(i32),
+    Enable,
+    Disable,
+}
+
+/// Something the interpreter can recognise in the memory dump: its name, then `(`, then `arity` numbers of 1 to 3 digits
+/// separated by `,`, then `)`. Anything else in the dump is noise.
+pub trait Instruction{
+    fn name(&self)->&str;
+    fn arity(&self)->usize;
+    /// Gets exactly [`Instruction::arity`] arguments.
+    fn execute(&self, args: &[i32])->Effect;
+}
+
+pub struct Mul;
+impl Instruction for Mul{
+    fn name(&self)->&str{
+        "mul"
+    }
+    fn arity(&self)->usize{
+        2
+    }
+    fn execute(&self, args: &[i32])->Effect{
+        Effect::Add(args[0]*args[1])
+    }
+}
+
+pub struct Do;
+impl Instruction for Do{
+    fn name(&self)->&str{
+        "do"
+    }
+    fn arity(&self)->usize{
+        0
+    }
+    fn execute(&self, _args: &[i32])->Effect{
+        Effect::Enable
+    }
+}
+
+pub struct Dont;
+impl Instruction for Dont{
+    fn name(&self)->&str{
+        "don't"
+    }
+    fn arity(&self)->usize{
+        0
+    }
+    fn execute(&self, _args: &[i32])->Effect{
+        Effect::Disable
+    }
+}
+
+/// One instruction found in the memory dump.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token{
+    /// Index into the instructions of the [`Interpreter`].
+    pub instruction: usize,
+    pub args: Vec<i32>,
+    /// Byte offsets of the instruction in the memory dump.
+    pub start: usize,
+    pub end: usize,
+}
+
+/// What happened when a [`Token`] was run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step{
+    pub token: Token,
+    pub effect: Effect,
+    /// Whether instructions were enabled when this one ran. Only enabled [`Effect::Add`]s count.
+    pub enabled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Trace{
+    pub steps: Vec<Step>,
+    pub sum: i32,
+}
+
+/// Finds the registered instructions in a memory dump and runs them in order.
+#[derive(Default)]
+pub struct Interpreter{
+    instructions: Vec<Box<dyn Instruction>>,
+}
+impl Interpreter{
+    /// An interpreter that does not know any instructions yet.
+    pub fn new()->Self{
+        Self::default()
+    }
+    /// Knows `mul`, `do` and `don't`.
+    pub fn standard()->Self{
+        Self::new().register(Mul).register(Do).register(Dont)
+    }
+    pub fn register(mut self, instruction: impl Instruction + 'static)->Self{
+        self.instructions.push(Box::new(instruction));
+        self
+    }
+    pub fn instruction(&self, token: &Token)->&dyn Instruction{
+        self.instructions[token.instruction].as_ref()
+    }
+    /// Every instruction in `memory`, in order. Instructions do not overlap: scanning goes on after the end of each one found.
+    pub fn tokenize(&self, memory: &str)->Vec<Token>{
+        let bytes = memory.as_bytes();
+        let mut tokens = Vec::new();
+        let mut start = 0;
+        while start < bytes.len(){
+            let found = self.instructions.iter().enumerate()
+                .find_map(|(instruction, known)| read_instruction(bytes, start, known.as_ref()).map(|(args, end)| Token{instruction, args, start, end}));
+            match found{
+                Some(token) => {
+                    start = token.end;
+                    tokens.push(token);
+                },
+                None => start += 1
+            }
+        }
+        tokens
+    }
+    /// Runs every instruction in `memory`, starting out enabled.
+    pub fn run(&self, memory: &str)->Trace{
+        let mut enabled = true;
+        let mut trace = Trace::default();
+        for token in self.tokenize(memory){
+            let effect = self.instruction(&token).execute(&token.args);
+            trace.steps.push(Step{token, effect, enabled});
+            match effect{
+                Effect::Add(value) if enabled => trace.sum += value,
+                Effect::Add(_) => (),
+                Effect::Enable => enabled = true,
+                Effect::Disable => enabled = false,
+            }
+        }
+        trace
+    }
+}
+
+/// Reads `instruction` at `start`, giving back its arguments and where it ends.
+fn read_instruction(bytes: &[u8], start: usize, instruction: &dyn Instruction)->Option<(Vec<i32>, usize)>{
+    let mut at = start;
+    let expect = |text: &[u8], at: &mut usize| -> Option<()>{
+        bytes[*at..].starts_with(text).then(|| *at += text.len())
+    };
+    expect(instruction.name().as_bytes(), &mut at)?;
+    expect(b"(", &mut at)?;
+    let mut args = Vec::with_capacity(instruction.arity());
+    for i in 0..instruction.arity(){
+        if i > 0{
+            expect(b",", &mut at)?;
+        }
+        let digits = bytes[at..].iter().take_while(|b| b.is_ascii_digit()).count();
+        if !(1..=3).contains(&digits){
+            return None;
+        }
+        args.push(bytes[at..at+digits].iter().fold(0, |n, b| n*10 + i32::from(b - b'0')));
+        at += digits;
+    }
+    expect(b")", &mut at)?;
+    Some((args, at))
+}
+
+pub fn solve1(s: &str)->i32{
+    Interpreter::new().register(Mul).run(s).sum
+}
+pub fn solve2(s: &str)->i32{
+    Interpreter::standard().run(s).sum
+}
+
+pub struct Day3;
+impl Solution for Day3{
+    type Input = String;
+    type Answer1 = i32;
+    type Answer2 = i32;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        Ok(Input::new(input).text().to_string())
+    }
+    fn part1(input: &Self::Input)->i32{
+        solve1(input)
+    }
+    fn part2(input: &Self::Input)->i32{
+        solve2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn first_part(){
+        let file_name = "TestData.txt";
+        let expected = 161;
+        let s = std::fs::read_to_string(file_name).unwrap();
+        let solution1 = solve1(&s);
+        assert_eq!(expected,solution1)
+    }
+    #[test]
+    fn second_part(){
+        let file_name = "TestData2.txt";
+        let expected = 48;
+        let s = std::fs::read_to_string(file_name).unwrap();
+        let solution2 = solve2(&s);
+        assert_eq!(expected,solution2)
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = std::fs::read_to_string("TestData2.txt").unwrap();
+        let data = Day3::parse(&s).unwrap();
+        assert_eq!(Day3::part1(&data),161);
+        assert_eq!(Day3::part2(&data),48);
+    }
+    #[test]
+    fn only_reads_whole_instructions(){
+        assert_eq!(solve1("mul(,)mul(2,)mul(1234,2)mul(4*mul(12,3)mul ( 2 , 3 )"), 36);
+        assert_eq!(solve1("mul(123,4)\nmul(2,3)"), 498);
+        // An instruction can not be split over lines.
+        assert_eq!(solve1("mul(2,\n3)"), 0);
+        assert_eq!(solve2("don't()\nmul(2,3)\ndo()mul(1,1)"), 1);
+    }
+    #[test]
+    fn traces_every_instruction(){
+        let trace = Interpreter::standard().run("xmul(2,4)don't()mul(5,5)do()");
+        let steps = trace.steps.iter().map(|step| (step.token.start, step.token.end, step.effect, step.enabled)).collect::<Vec<_>>();
+        assert_eq!(steps, [(1, 9, Effect::Add(8), true), (9, 16, Effect::Disable, true), (16, 24, Effect::Add(25), false), (24, 28, Effect::Enable, false)]);
+        assert_eq!(trace.sum, 8);
+        assert_eq!(trace.steps[1].token.args, Vec::<i32>::new());
+    }
+    struct Sub;
+    impl Instruction for Sub{
+        fn name(&self)->&str{
+            "sub"
+        }
+        fn arity(&self)->usize{
+            2
+        }
+        fn execute(&self, args: &[i32])->Effect{
+            Effect::Add(args[0]-args[1])
+        }
+    }
+    #[test]
+    fn registers_extra_instructions(){
+        let interpreter = Interpreter::standard().register(Sub);
+        let trace = interpreter.run("mul(3,3)sub(2,10)don't()sub(1,0)");
+        assert_eq!(trace.sum, 1);
+        assert_eq!(trace.steps.iter().map(|step| interpreter.instruction(&step.token).name()).collect::<Vec<_>>(), ["mul", "sub", "don't", "sub"]);
+    }
+}
\ No newline at end of file
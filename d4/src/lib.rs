@@ -0,0 +1,157 @@
+use std::fmt::Display;
+
+use support::{direction::Direction8, input::Input, matrix::{GetSet, Matrix}, parse::ParseError, position::Position};
+impl From<Letter> for char{
+    fn from(value: Letter) -> Self {
+        match value{
+            Letter::X => 'X',
+            Letter::M => 'M',
+            Letter::A => 'A',
+            Letter::S => 'S',
+            Letter::None => '_',
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Letter{
+    X,
+    M,
+    A,
+    S,
+    None
+}
+
+impl From<char> for Letter{
+    fn from(value: char) -> Self {
+        match value{
+            'X' => Self::X,
+            'M' => Self::M,
+            'A' => Self::A,
+            'S' => Self::S, 
+            _ =>Self::None 
+        }
+    }
+}
+impl Display for Letter{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Letter::X => write!(f,"X"),
+            Letter::M => write!(f,"M"),
+            Letter::A => write!(f,"A"),
+            Letter::S => write!(f,"S"),
+            Letter::None => write!(f,"_"),
+        }
+    }
+}
+
+pub fn get_matrix_from_string(s: &str)->Result<Matrix<Letter>,ParseError>{
+    let input = Input::new(s);
+    let matrix = input.grid(|c| Some(Letter::from(c)), "a letter")?;
+    Ok(Matrix::new_from_square(matrix))
+}
+
+const XMAS: [Letter;4] = [Letter::X, Letter::M, Letter::A, Letter::S];
+
+/// How many times `word` can be read by starting on any tile and walking in one of `directions`.
+pub fn count_word(matrix:&Matrix<Letter>, word:&[Letter], directions: &[Direction8])->i32{
+    let mut counter = 0;
+    for (start,_) in matrix.enumerate(){
+        for direction in directions{
+            let step = direction.as_vector();
+            let found = word.iter().enumerate().all(|(i,letter)| matrix.get(start + step*(i as i32)) == Some(*letter));
+            if found{
+                counter += 1;
+            }
+        }
+    }
+    counter
+}
+pub fn solve1(matrix:&Matrix<Letter>)->i32{
+    count_word(matrix, &XMAS, &Direction8::ALL)
+}
+pub fn solve2(matrix:&Matrix<Letter>)->i32{
+    use Letter::*;
+    // Both diagonals through an 'A' have to read "MAS", in either direction.
+    let is_mas = |pos: Position<i32>, direction: Direction8|{
+        let ends = [matrix.get(pos + direction.as_vector()), matrix.get(pos + direction.opposite().as_vector())];
+        ends == [Some(M),Some(S)] || ends == [Some(S),Some(M)]
+    };
+    matrix.find_all(&A)
+        .filter(|&pos| is_mas(pos, Direction8::NW) && is_mas(pos, Direction8::NE))
+        .count() as i32
+}
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use super::*;
+    #[test]
+    fn diagonal_left_test(){
+        let expected = 1;
+        let s = "XXXX\nMMMM\nAAAA\nSSSS";
+        let matrix = get_matrix_from_string(s).unwrap();
+        println!("--- Test Input ---");
+        print!("{matrix}");
+        println!("------------------");
+        let actual = count_word(&matrix, &XMAS, &[Direction8::SE, Direction8::NW]);
+        assert_eq!(expected,actual,"Found {actual}, expected {expected}");
+    }
+    #[test]
+    fn diagonal_right_test(){
+        let expected = 1;
+        let s = "XXXX\nMMMM\nAAAA\nSSSS";
+        let matrix = get_matrix_from_string(s).unwrap();
+        println!("--- Test Input ---");
+        print!("{matrix}");
+        println!("------------------");
+        let actual = count_word(&matrix, &XMAS, &[Direction8::SW, Direction8::NE]);
+        assert_eq!(expected,actual,"Found {actual}, expected {expected}");
+    }
+    #[test]
+    fn vertical_test(){
+        let expected = 3;
+        let s = "XXXX\nMMMM\nAAAA\nSXSS";
+        let matrix = get_matrix_from_string(s).unwrap();
+        println!("--- Test Input ---");
+        print!("{matrix}");
+        println!("------------------");
+        let actual = count_word(&matrix, &XMAS, &[Direction8::S, Direction8::N]);
+        assert_eq!(expected,actual,"Found {actual}, expected {expected}");
+    }
+    #[test]
+    fn horizontal_test(){
+        let expected = 2;
+        let s = "XMAS\nXMAS\nAAAA\nSSSS";
+        let matrix = get_matrix_from_string(s).unwrap();
+        println!("--- Test Input ---");
+        print!("{matrix}");
+        println!("------------------");
+        let actual = count_word(&matrix, &XMAS, &[Direction8::E, Direction8::W]);
+        assert_eq!(expected,actual,"Found {actual}, expected {expected}");
+    }
+    #[test]
+    fn rejects_ragged_grid(){
+        let e = get_matrix_from_string("XMAS\nXMA\nAAAA\nSSSS").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 1, "XMA"));
+    }
+    #[test]
+    fn solves_test_1(){
+        let file_name = "TestData1.txt";
+        let expected = 18;
+        let s = read_to_string(file_name).unwrap();
+        let matrix = get_matrix_from_string(&s).unwrap();
+        let actual = solve1(&matrix);
+        assert_eq!(expected,actual);
+    }
+    #[test]
+    fn solves_test_2(){
+        let file_name = "TestData1.txt";
+        let expected = 9;
+        let s = read_to_string(file_name).unwrap();
+        let matrix = get_matrix_from_string(&s).unwrap();
+        let actual = solve2(&matrix);
+        assert_eq!(expected,actual);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,260 @@
+use std::{collections::{HashMap, HashSet}, fmt::Display, fs::read_to_string};
+
+use support::{input::Input, parse::{key_value, number, separated, ParseError}, solution::Solution};
+
+type Rules = HashMap<usize,Vec<usize>>;
+type Updates = Vec<Vec<usize>>;
+pub fn parse_file(file_name: &str)->Result<(Rules,Updates),ParseError>{
+    let s = read_to_string(file_name).unwrap();
+    get_data(&s)
+}
+pub fn get_data(s: &str)->Result<(Rules,Updates),ParseError>{
+    let input = Input::new(s);
+    let mut blocks = input.blocks();
+    let (Some(rule_lines), Some(update_lines), None) = (blocks.next(), blocks.next(), blocks.next()) else {
+        return Err(ParseError::whole_input("expected a block of ordering rules and a block of updates"));
+    };
+    let mut rules: HashMap<usize, Vec<usize>> = HashMap::new();
+    for line in rule_lines{
+        let (left,right) = key_value(input.text(), line, "|")?;
+        let a = number(input.text(), left)?;
+        let b = number(input.text(), right)?;
+        rules.entry(a).or_default().push(b);
+    }
+    let updates = update_lines.into_iter().map(|line| separated(input.text(), line, ',')).collect::<Result<Updates,ParseError>>()?;
+    Ok((rules,updates))
+}
+/// The ordering rules as a graph, with an edge from `a` to `b` for every `a|b`.
+///
+/// The full rule set does not have to be a proper order; only the rules between the pages of one update matter for it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RuleGraph{
+    after: HashMap<usize, HashSet<usize>>
+}
+
+/// Rules that contradict each other: every page in `pages` has to come before the next one, and the last before the first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle{
+    pub pages: Vec<usize>
+}
+impl Display for Cycle{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let pages = self.pages.iter().map(usize::to_string).collect::<Vec<String>>();
+        write!(f, "the ordering rules go round in a circle: {} -> {}", pages.join(" -> "), pages.first().map_or("", String::as_str))
+    }
+}
+
+impl RuleGraph{
+    pub fn new(rules: &Rules)->Self{
+        let after = rules.iter().map(|(&page, later)| (page, later.iter().copied().collect())).collect();
+        Self{after}
+    }
+    /// Whether there is a rule saying `a` comes before `b`.
+    pub fn must_precede(&self, a: usize, b: usize)->bool{
+        self.after.get(&a).is_some_and(|later| later.contains(&b))
+    }
+    /// Whether `update` follows every rule between the pages in it.
+    pub fn is_valid(&self, update: &[usize])->bool{
+        update.iter().enumerate().all(|(i, &page)| update[..i].iter().all(|&earlier| !self.must_precede(page, earlier)))
+    }
+    /// The pages of `update` in an order that follows the rules between them. Pages no rule says anything about
+    /// keep their order. Fails if those rules go round in a circle.
+    pub fn sort(&self, update: &[usize])->Result<Vec<usize>,Cycle>{
+        let mut waiting_for = update.iter()
+            .map(|&page| update.iter().filter(|&&other| self.must_precede(other, page)).count())
+            .collect::<Vec<usize>>();
+        let mut done = vec![false; update.len()];
+        let mut sorted = Vec::with_capacity(update.len());
+        while let Some(next) = (0..update.len()).find(|&i| !done[i] && waiting_for[i] == 0){
+            done[next] = true;
+            sorted.push(update[next]);
+            for (i, &page) in update.iter().enumerate(){
+                if self.must_precede(update[next], page){
+                    waiting_for[i] -= 1;
+                }
+            }
+        }
+        if sorted.len() == update.len(){
+            return Ok(sorted);
+        }
+        let stuck = (0..update.len()).filter(|&i| !done[i]).map(|i| update[i]).collect::<Vec<usize>>();
+        Err(self.cycle_among(&stuck).expect("Pages that can not be sorted are waiting on each other."))
+    }
+    /// A circle in the whole rule set, if there is one.
+    pub fn find_cycle(&self)->Option<Cycle>{
+        let mut pages = self.after.iter().flat_map(|(&page, later)| later.iter().copied().chain([page])).collect::<Vec<usize>>();
+        pages.sort();
+        pages.dedup();
+        self.cycle_among(&pages)
+    }
+    /// A circle using only the rules between `pages`, found with a depth first search.
+    fn cycle_among(&self, pages: &[usize])->Option<Cycle>{
+        let pages = pages.iter().copied().collect::<HashSet<usize>>();
+        let mut finished = HashSet::new();
+        let mut sorted_pages = pages.iter().copied().collect::<Vec<usize>>();
+        sorted_pages.sort();
+        for start in sorted_pages{
+            if finished.contains(&start){
+                continue;
+            }
+            // The pages on the current path, each with the rules from it that are left to follow.
+            let mut path = vec![start];
+            let mut to_follow = vec![self.successors(start, &pages)];
+            while let Some(next) = to_follow.last_mut().map(Vec::pop){
+                match next{
+                    Some(page) if let Some(at) = path.iter().position(|&p| p == page) => return Some(Cycle{pages: path[at..].to_vec()}),
+                    Some(page) if finished.contains(&page) => (),
+                    Some(page) => {
+                        path.push(page);
+                        to_follow.push(self.successors(page, &pages));
+                    },
+                    None => {
+                        finished.extend(path.pop());
+                        to_follow.pop();
+                    }
+                }
+            }
+        }
+        None
+    }
+    fn successors(&self, page: usize, pages: &HashSet<usize>)->Vec<usize>{
+        let mut later = self.after.get(&page).into_iter().flatten().copied().filter(|p| pages.contains(p)).collect::<Vec<usize>>();
+        later.sort_by(|a, b| b.cmp(a));
+        later
+    }
+}
+
+fn middle(update: &[usize])->usize{
+    update[update.len()/2]
+}
+pub fn solve1(rules: &Rules, updates: &Updates)->usize{
+    let graph = RuleGraph::new(rules);
+    updates.iter()
+        .filter(|line| !line.is_empty())
+        .filter(|line| graph.is_valid(line))
+        .map(|line| middle(line))
+        .sum::<usize>()
+}
+/// Fails with the first circle in the rules between the pages of an update that has to be repaired.
+pub fn solve2(rules: &Rules, updates: &Updates)->Result<usize,Cycle>{
+    let graph = RuleGraph::new(rules);
+    updates.iter()
+        .filter(|line| !line.is_empty())
+        .filter(|line| !graph.is_valid(line))
+        .map(|line| graph.sort(line).map(|line| middle(&line)))
+        .sum()
+}
+
+/// The answer to part 2: the sum of the middle pages of the repaired updates, or why they could not be repaired.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repaired{
+    Sum(usize),
+    Cycle(Cycle)
+}
+impl From<Result<usize,Cycle>> for Repaired{
+    fn from(value: Result<usize,Cycle>) -> Self {
+        match value{
+            Ok(sum) => Self::Sum(sum),
+            Err(cycle) => Self::Cycle(cycle)
+        }
+    }
+}
+impl Display for Repaired{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Self::Sum(sum) => write!(f, "{sum}"),
+            Self::Cycle(cycle) => write!(f, "{cycle}")
+        }
+    }
+}
+
+pub struct Day5;
+impl Solution for Day5{
+    type Input = (Rules,Updates);
+    type Answer1 = usize;
+    type Answer2 = Repaired;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        get_data(input)
+    }
+    fn part1((rules, updates): &Self::Input)->usize{
+        solve1(rules, updates)
+    }
+    fn part2((rules, updates): &Self::Input)->Repaired{
+        solve2(rules, updates).into()
+    }
+}
+
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    #[test]
+    fn test1(){
+        let file_name = "TestData1.txt";
+        let (rules, updates) = parse_file(file_name).unwrap();
+        let solution1 = solve1(&rules, &updates);
+        assert_eq!(solution1,143);
+    }
+    #[test]
+    fn test2(){
+        let file_name = "TestData1.txt";
+        let (rules, updates) = parse_file(file_name).unwrap();
+        let solution1 = solve2(&rules, &updates);
+        assert_eq!(solution1,Ok(123));
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = Day5::parse(&s).unwrap();
+        assert_eq!(Day5::part1(&data),143);
+        assert_eq!(Day5::part2(&data),Repaired::Sum(123));
+    }
+    #[test]
+    fn repairs_updates(){
+        let (rules, _) = parse_file("TestData1.txt").unwrap();
+        let graph = RuleGraph::new(&rules);
+        assert!(graph.is_valid(&[75,47,61,53,29]));
+        assert!(!graph.is_valid(&[75,97,47,61,53]));
+        assert_eq!(graph.sort(&[75,97,47,61,53]), Ok(vec![97,75,47,61,53]));
+        assert_eq!(graph.sort(&[61,13,29]), Ok(vec![61,29,13]));
+        assert_eq!(graph.sort(&[97,13,75,29,47]), Ok(vec![97,75,47,29,13]));
+        assert_eq!(graph.find_cycle(), None);
+    }
+    #[test]
+    fn handles_large_page_numbers(){
+        let (rules, updates) = get_data("150|7\n7|1000\n\n150,7,1000\n1000,7,150,3\n").unwrap();
+        assert_eq!(solve1(&rules, &updates), 7);
+        assert_eq!(solve2(&rules, &updates), Ok(1000));
+        assert_eq!(RuleGraph::new(&rules).sort(&updates[1]), Ok(vec![150,7,1000,3]));
+    }
+    #[test]
+    fn reports_cycles(){
+        let (rules, updates) = get_data("1|2\n2|3\n3|1\n3|4\n\n4,3,2\n2,1,3,4\n").unwrap();
+        let graph = RuleGraph::new(&rules);
+        // Only the rules between the pages of an update count.
+        assert_eq!(graph.sort(&updates[0]), Ok(vec![2,3,4]));
+        let cycle = graph.sort(&updates[1]).unwrap_err();
+        assert_eq!(cycle, Cycle{pages: vec![1,2,3]});
+        assert_eq!(cycle.to_string(), "the ordering rules go round in a circle: 1 -> 2 -> 3 -> 1");
+        assert_eq!(graph.find_cycle(), Some(cycle.clone()));
+        assert_eq!(solve2(&rules, &updates), Err(cycle.clone()));
+        // Part 1 never sorts, so it still has an answer.
+        let data = Day5::parse("1|2\n2|3\n3|1\n3|4\n\n4,3,2\n2,1,3,4\n").unwrap();
+        assert_eq!(Day5::part1(&data), 0);
+        assert_eq!(Day5::part2(&data), Repaired::Cycle(cycle));
+        assert_eq!(Day5::part2(&data).to_string(), "the ordering rules go round in a circle: 1 -> 2 -> 3 -> 1");
+    }
+    #[test]
+    fn windows_line_endings(){
+        let unix = get_data("47|53\n97|13\n\n75,47,53\n").unwrap();
+        let windows = get_data("47|53\r\n97|13\r\n\r\n75,47,53\r\n").unwrap();
+        assert_eq!(unix, windows);
+    }
+    #[test]
+    fn reports_bad_numbers(){
+        let e = get_data("47|53\n97|x3\n\n75,47\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 4, "x3"));
+        let e = get_data("47|53\n\n75,47,,53\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (3, 7, ""));
+    }
+}
\ No newline at end of file
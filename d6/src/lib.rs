@@ -0,0 +1,434 @@
+use std::{cmp::Reverse, collections::{HashMap, HashSet}, fmt::Display, fs::read_to_string};
+
+use support::{direction::Direction, input::Input, matrix::{self, GetSet}, parse::ParseError, position::Position};
+
+type Grid = matrix::Matrix<Tile, usize>;
+type Pos = Position<usize>;
+/// A guard: where it stands and where it is heading.
+pub type Guard = (Pos, Direction);
+
+#[derive(Debug,Clone,Copy,PartialEq,Eq, PartialOrd, Ord, Hash)]
+enum Tile{
+    Guard(Direction),
+    Wall,
+    CustomWall,
+    Space,
+    Visited
+}
+impl Display for Tile{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self{
+            Tile::Guard(Direction::Up) => '^',
+            Tile::Guard(Direction::Right) => '>',
+            Tile::Guard(Direction::Down) => 'v',
+            Tile::Guard(Direction::Left) => '<',
+            Tile::Guard(Direction::None) => 'O',
+            Tile::Wall => '#',
+            Tile::CustomWall => 'X',
+            Tile::Space => ' ',
+            Tile::Visited => '.'
+        };
+        write!(f,"{c}")
+    }
+}
+impl Tile{
+    fn new(c: char)->Option<Self>{
+        match c{
+            '.' => Some(Self::Space),
+            '^' => Some(Self::Guard(Direction::Up)),
+            '>' => Some(Self::Guard(Direction::Right)),
+            'v' => Some(Self::Guard(Direction::Down)),
+            '<' => Some(Self::Guard(Direction::Left)),
+            '#' => Some(Self::Wall),
+            _ => None
+        }
+    }
+    fn is_blocking(&self)->bool{
+        matches!(self, Tile::Wall | Tile::CustomWall)
+    }
+}
+
+/// The index of `direction` in the jump table, in the order the guard turns.
+fn heading(direction: Direction)->usize{
+    match direction{
+        Direction::Up => 0,
+        Direction::Right => 1,
+        Direction::Down => 2,
+        Direction::Left => 3,
+        Direction::None => unreachable!("Guards always face somewhere.")
+    }
+}
+/// How far along `direction` `pos` is, and where it is across it. Two positions on the same line in that
+/// direction share `across`, and the one further ahead has the larger `along`.
+fn along_across(pos: Pos, direction: Direction)->(i64, i64){
+    let vector = direction.as_vector();
+    let (x, y, dx, dy) = (pos.x() as i64, pos.y() as i64, vector.x() as i64, vector.y() as i64);
+    (x*dx + y*dy, x*dy - y*dx)
+}
+
+/// Where a guard went, step by step, and the part it keeps repeating if it never leaves the map.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Patrol{
+    path: Vec<Guard>,
+    cycle: Option<Vec<Guard>>
+}
+impl Patrol{
+    /// Every position and heading the guard had, in order. A turn on the spot is a step of its own.
+    pub fn path(&self)->&[Guard]{
+        &self.path
+    }
+    /// The steps that repeat forever, starting with the first one that was seen twice.
+    pub fn cycle(&self)->Option<&[Guard]>{
+        self.cycle.as_deref()
+    }
+    pub fn is_loop(&self)->bool{
+        self.cycle.is_some()
+    }
+    pub fn cells(&self)->HashSet<Pos>{
+        self.path.iter().map(|&(pos,_)| pos).collect()
+    }
+}
+
+#[derive(Clone,Debug)]
+pub struct Matrix{
+    grid: Grid,
+    guards: Vec<Guard>,
+    /// For every tile and heading: where a guard walking that way stops in front of a wall, `None` if it walks off the map.
+    jumps: matrix::Matrix<[Option<Pos>;4], usize>
+}
+impl Matrix{
+    pub fn from_string(s:&str)->Result<Self,ParseError>{
+        let mut grid = Grid::new_from_square(Input::new(s).grid(Tile::new, "'.', '#' or a guard ('^', '>', 'v' or '<')")?);
+        let guards = grid.enumerate()
+            .filter_map(|(pos, tile)| match tile{
+                Tile::Guard(direction) => Some((pos, *direction)),
+                _ => None
+            }).collect::<Vec<Guard>>();
+        if guards.is_empty(){
+            return Err(ParseError::whole_input("there is no guard ('^', '>', 'v' or '<') on the map"));
+        }
+        for &(pos,_) in &guards{
+            grid.set(pos, Tile::Space);
+        }
+        let jumps = Self::jump_table(&grid);
+        Ok(Self{grid, guards, jumps})
+    }
+    pub fn from_file(file_path: &str)->Result<Self,ParseError>{
+        let s = read_to_string(file_path).unwrap();
+        Self::from_string(&s)
+    }
+    fn jump_table(grid: &Grid)->matrix::Matrix<[Option<Pos>;4], usize>{
+        let mut jumps = grid.map(|_| [None;4]);
+        for direction in [Direction::Up, Direction::Right, Direction::Down, Direction::Left]{
+            // The tiles closest to the edge the guard walks towards go first, so the tile ahead is always done.
+            let mut tiles = grid.enumerate().map(|(pos,_)| pos).collect::<Vec<Pos>>();
+            tiles.sort_by_key(|&pos| Reverse(along_across(pos, direction).0));
+            for pos in tiles{
+                let stop = match grid.step(pos, direction){
+                    None => None,
+                    Some(next) if grid.get(next).is_some_and(|tile| tile.is_blocking()) => Some(pos),
+                    Some(next) => jumps.get(next).and_then(|jump| jump[heading(direction)])
+                };
+                let mut jump = jumps.get(pos).unwrap_or_default();
+                jump[heading(direction)] = stop;
+                jumps.set(pos, jump);
+            }
+        }
+        jumps
+    }
+    #[cfg(test)]
+    fn get_pos(&self, position: &Pos)->Option<Tile>{
+        self.grid.get(*position)
+    }
+    #[cfg(test)]
+    fn set_pos(&mut self, position: &Pos, value: Tile){
+        self.grid.set(*position, value);
+    }
+    #[cfg(test)]
+    fn new_pos(&self,y:usize,x:usize)->Option<Pos>{
+        let pos = Position::new(x, y);
+        self.grid.is_pos_valid(pos).then_some(pos)
+    }
+    pub fn guards(&self)->&[Guard]{
+        &self.guards
+    }
+    fn is_blocked(&self, pos: Pos, obstruction: Option<Pos>)->bool{
+        obstruction == Some(pos) || self.grid.get(pos).is_some_and(|tile| tile.is_blocking())
+    }
+    /// Follows `guard` one step at a time, with an extra wall at `obstruction` if there is one.
+    pub fn patrol(&self, guard: Guard, obstruction: Option<Pos>)->Patrol{
+        let mut path = vec![guard];
+        let mut seen = HashMap::from([(guard, 0)]);
+        let (mut pos, mut direction) = guard;
+        while let Some(next) = self.grid.step(pos, direction){
+            if self.is_blocked(next, obstruction){
+                direction = direction.rot_right();
+            }else{
+                pos = next;
+            }
+            if let Some(&start) = seen.get(&(pos, direction)){
+                let cycle = Some(path[start..].to_vec());
+                return Patrol{path, cycle};
+            }
+            seen.insert((pos, direction), path.len());
+            path.push((pos, direction));
+        }
+        Patrol{path, cycle: None}
+    }
+    /// Where a guard at `pos` walking towards `direction` stops, `None` if it walks off the map.
+    fn jump(&self, pos: Pos, direction: Direction, obstruction: Option<Pos>)->Option<Pos>{
+        let stop = self.jumps.get(pos)?[heading(direction)];
+        let Some(obstruction) = obstruction else{
+            return stop;
+        };
+        let (start, line) = along_across(pos, direction);
+        let (ahead, obstruction_line) = along_across(obstruction, direction);
+        let in_the_way = obstruction_line == line && ahead > start
+            && stop.is_none_or(|stop| ahead <= along_across(stop, direction).0);
+        match in_the_way{
+            true => self.grid.step(obstruction, direction.flip()),
+            false => stop
+        }
+    }
+    /// Whether `guard` ends up walking in circles with an extra wall at `obstruction`. Only looks at where the guard turns.
+    fn loops_with(&self, guard: Guard, obstruction: Pos)->bool{
+        let mut turns = HashSet::new();
+        let (mut pos, mut direction) = guard;
+        while let Some(stop) = self.jump(pos, direction, Some(obstruction)){
+            if !turns.insert((stop, direction)){
+                return true;
+            }
+            pos = stop;
+            direction = direction.rot_right();
+        }
+        false
+    }
+    /// Every tile at least one guard walks over.
+    pub fn visited(&self)->HashSet<Pos>{
+        self.guards.iter().flat_map(|&guard| self.patrol(guard, None).cells()).collect()
+    }
+    /// Every tile where one extra wall makes at least one guard walk in circles, sorted. Tiles with a guard on them are left out.
+    ///
+    /// A wall only matters where a guard would have walked, and the guard's path up to that tile stays the same,
+    /// so each check starts from the tile just in front of the new wall.
+    pub fn loop_obstructions(&self)->Vec<Pos>{
+        let starts = self.guards.iter().map(|&(pos,_)| pos).collect::<HashSet<Pos>>();
+        let mut found = HashSet::new();
+        for &guard in &self.guards{
+            let patrol = self.patrol(guard, None);
+            let mut tried = HashSet::new();
+            for step in patrol.path().windows(2){
+                let ((from, direction), (to, _)) = (step[0], step[1]);
+                if from == to || starts.contains(&to) || !tried.insert(to){
+                    continue;
+                }
+                if self.loops_with((from, direction), to){
+                    found.insert(to);
+                }
+            }
+        }
+        let mut found = found.into_iter().collect::<Vec<Pos>>();
+        found.sort();
+        found
+    }
+    /// The map with `patrol`, the guards and `obstruction` drawn on it.
+    pub fn draw(&self, patrol: &Patrol, obstruction: Option<Pos>)->String{
+        let mut grid = self.grid.clone();
+        for &(pos,_) in patrol.path(){
+            grid.set(pos, Tile::Visited);
+        }
+        if let Some(obstruction) = obstruction{
+            grid.set(obstruction, Tile::CustomWall);
+        }
+        for &(pos, direction) in &self.guards{
+            grid.set(pos, Tile::Guard(direction));
+        }
+        grid.to_string()
+    }
+    pub fn solve1(&self)->i32{
+        self.visited().len() as i32
+    }
+    pub fn solve2(&self)->i32{
+        self.loop_obstructions().len() as i32
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+    mod solve_cases{
+        use super::*;
+        #[test]
+        fn solves1(){
+            let expected = 41;
+            let file_name = "TestData1.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let actual = matrix.solve1();
+            assert_eq!(actual,expected);
+        }
+        #[test]
+        fn solves2(){
+            let expected = 6;
+            let file_name = "TestData1.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let actual = matrix.solve2();
+            assert_eq!(actual,expected);
+        }
+        #[test]
+        fn solves_ivars_case(){
+            let expected = 5331;
+            let file_name = "Ivar_input.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let actual = matrix.solve1();
+            assert_eq!(actual,expected);
+        }
+    }
+    mod detect_loops{
+            use super::*;
+        #[test]
+        fn can_detect_loops(){
+
+            let file_name = "Looping.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let actual = matrix.patrol(matrix.guards()[0], None);
+            assert!(actual.is_loop())
+        }
+        #[test]
+        fn can_detect_tiny_loops(){
+            let file_name = "Looping_small.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let actual = matrix.patrol(matrix.guards()[0], None);
+            assert!(actual.is_loop())
+        }
+        #[test]
+        fn can_detect_non_loops(){
+            let file_name = "Not_looping.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let actual = matrix.patrol(matrix.guards()[0], None);
+            assert!(!actual.is_loop())
+        }
+    }
+    mod create_loops{
+        use super::*;
+        #[test]
+        fn can_create_tiny_loops(){
+            let expected = 1;
+            let file_name = "Almost_looping_small.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let initial = matrix.patrol(matrix.guards()[0], None);
+            assert!(!initial.is_loop());
+            let n_blocking = matrix.solve2();
+            let expected_wall_position = matrix.new_pos(1, 2).unwrap();
+            assert_eq!(matrix.loop_obstructions(), [expected_wall_position]);
+            assert_eq!(matrix.guards(), [(matrix.new_pos(1, 1).unwrap(), Direction::Up)]);
+            assert_eq!(n_blocking,expected);
+        }
+        #[test]
+        fn can_create_loops(){
+            let expected = 1;
+            let file_name = "Almost_looping.txt";
+            let matrix = Matrix::from_file(file_name).unwrap();
+            let expected_pos = matrix.new_pos(1, 1).expect("Known to exist");
+            assert_eq!(matrix.guards()[0].0,expected_pos);
+            let initial = matrix.patrol(matrix.guards()[0], None);
+            assert!(!initial.is_loop());
+            let n_blocking = matrix.solve2();
+
+            assert_eq!(n_blocking,expected);
+        }
+    }
+    mod obstructions{
+        use super::*;
+        #[test]
+        fn finds_the_example_positions(){
+            let matrix = Matrix::from_file("TestData1.txt").unwrap();
+            let expected = [(1,8),(3,6),(3,8),(6,7),(7,7),(7,9)].map(|(x,y)| Position::new(x,y));
+            assert_eq!(matrix.loop_obstructions(), expected);
+        }
+        #[test]
+        fn agrees_with_walking_every_step(){
+            for file_name in ["TestData1.txt", "Almost_looping.txt", "Not_looping.txt"]{
+                let matrix = Matrix::from_file(file_name).unwrap();
+                let guard = matrix.guards()[0];
+                let mut brute_force = matrix.patrol(guard, None).cells().into_iter()
+                    .filter(|&pos| pos != guard.0 && matrix.patrol(guard, Some(pos)).is_loop())
+                    .collect::<Vec<Pos>>();
+                brute_force.sort();
+                assert_eq!(matrix.loop_obstructions(), brute_force, "{file_name}");
+            }
+        }
+        #[test]
+        fn exposes_the_cycle(){
+            let matrix = Matrix::from_file("TestData1.txt").unwrap();
+            let obstruction = Position::new(3, 6);
+            let patrol = matrix.patrol(matrix.guards()[0], Some(obstruction));
+            let cycle = patrol.cycle().unwrap();
+            assert_eq!(cycle.first(), Some(&(Position::new(4, 6), Direction::Up)));
+            assert!(cycle.iter().all(|step| patrol.path().contains(step)));
+            assert!(matrix.draw(&patrol, Some(obstruction)).lines().nth(6).unwrap().contains("X^"));
+        }
+        #[test]
+        fn handles_other_glyphs_and_more_guards(){
+            let matrix = Matrix::from_string(".#..\n...#\n>...\n..^.").unwrap();
+            assert_eq!(matrix.guards(), [(Position::new(0,2), Direction::Right), (Position::new(2,3), Direction::Up)]);
+            assert_eq!(matrix.patrol(matrix.guards()[0], None).cells().len(), 4);
+            assert_eq!(matrix.solve1(), 7);
+            let down = Matrix::from_string("..v\n...\n").unwrap();
+            assert_eq!(down.patrol(down.guards()[0], None).path(), [(Position::new(2,0), Direction::Down), (Position::new(2,1), Direction::Down)]);
+        }
+    }
+    mod place_walls{
+        use super::*;
+        #[test]
+        fn can_place_custom_walls(){
+            let s = "..\n^.";
+            let mut matrix = Matrix::from_string(s).unwrap();
+            let p = matrix.new_pos(0, 0).unwrap();
+            matrix.set_pos(&p, Tile::CustomWall);
+            let expected = Tile::CustomWall;
+            let actual = matrix.get_pos(&p).unwrap();
+            assert_eq!(expected,actual)
+        }
+        #[test]
+        fn can_place_custom_walls2(){
+            let s = ".\n.\n.\n.\n.\n.\n.\n.\n.\n.\n.\n.\n^\n.";
+            let mut matrix = Matrix::from_string(s).unwrap();
+            let p = matrix.new_pos(11, 0).unwrap();
+            matrix.set_pos(&p, Tile::CustomWall);
+            let expected = Tile::CustomWall;
+            let actual = matrix.get_pos(&p).unwrap();
+            assert_eq!(expected,actual)
+        }
+        #[test]
+        fn can_place_custom_walls3(){
+            let s = "............^.";
+            let mut matrix = Matrix::from_string(s).unwrap();
+            let p = matrix.new_pos(0, 11).unwrap();
+            matrix.set_pos(&p, Tile::CustomWall);
+            let expected = Tile::CustomWall;
+            let actual = matrix.get_pos(&p).unwrap();
+            assert_eq!(expected,actual)
+        }
+    }
+
+    #[test]
+    fn idx_conversion(){
+        let file_name = "TestData1.txt";
+        let matrix = Matrix::from_file(file_name).unwrap();
+        for i in 0..matrix.grid.width()*matrix.grid.height(){
+            let pos = matrix.grid.from_idx(i).unwrap();
+            let idx = matrix.grid.to_idx(pos);
+            assert_eq!(Some(i),idx);
+        }
+    }
+    #[test]
+    fn reports_bad_maps(){
+        let e = Matrix::from_string("..#\n.^x\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 3, "x"));
+        let e = Matrix::from_string("..#\n.^\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 1, ".^"));
+        let e = Matrix::from_string("..#\n...\n").unwrap_err();
+        assert_eq!(e.line(), 0);
+    }
+
+}
@@ -0,0 +1,266 @@
+
+use support::{input::Input, parse::{key_value, number, whitespace_separated, ParseError}, solution};
+
+type Solution = u64;
+/// Something that can go between two numbers of an equation. Equations are always worked out left to right.
+pub trait Operator{
+    fn symbol(&self)->&str;
+    /// `left <op> right`, or `None` if that does not fit in a [`Solution`].
+    fn apply(&self, left: Solution, right: Solution)->Option<Solution>;
+    /// Works out the `left` that gives `result` with this `right`: `Some(None)` if there is no such `left`.
+    /// Operators that can not be undone keep the default `None`, and are tried forwards instead.
+    fn undo(&self, _result: Solution, _right: Solution)->Option<Option<Solution>>{
+        None
+    }
+}
+
+pub struct Add;
+impl Operator for Add{
+    fn symbol(&self)->&str{
+        "+"
+    }
+    fn apply(&self, left: Solution, right: Solution)->Option<Solution>{
+        left.checked_add(right)
+    }
+    fn undo(&self, result: Solution, right: Solution)->Option<Option<Solution>>{
+        Some(result.checked_sub(right))
+    }
+}
+
+pub struct Multiply;
+impl Operator for Multiply{
+    fn symbol(&self)->&str{
+        "*"
+    }
+    fn apply(&self, left: Solution, right: Solution)->Option<Solution>{
+        left.checked_mul(right)
+    }
+    fn undo(&self, result: Solution, right: Solution)->Option<Option<Solution>>{
+        match right{
+            // Anything times 0 is 0, so there is no single `left` to give back.
+            0 => None,
+            _ => Some(result.is_multiple_of(right).then_some(result / right))
+        }
+    }
+}
+
+/// `||`: the digits of `right` written after the digits of `left`.
+pub struct Concatenate;
+impl Concatenate{
+    /// 10 to the power of the number of digits in `right`.
+    fn shift(right: Solution)->Option<Solution>{
+        10_u64.checked_pow(right.checked_ilog10().unwrap_or(0) + 1)
+    }
+}
+impl Operator for Concatenate{
+    fn symbol(&self)->&str{
+        "||"
+    }
+    fn apply(&self, left: Solution, right: Solution)->Option<Solution>{
+        left.checked_mul(Self::shift(right)?)?.checked_add(right)
+    }
+    fn undo(&self, result: Solution, right: Solution)->Option<Option<Solution>>{
+        let Some(shift) = Self::shift(right) else{
+            return Some(None);
+        };
+        Some((result % shift == right).then_some(result / shift))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Equation{
+    left: Solution,
+    right: Vec<Solution>
+}
+impl Equation{
+    fn new(source:&str, s:&str)->Result<Self,ParseError>{
+        let (left_s,right_s) = key_value(source, s, ":")?;
+        let left = number(source, left_s)?;
+        let right: Vec<Solution> = whitespace_separated(source, right_s)?;
+        if right.len() < 2{
+            return Err(ParseError::at(source, right_s, "expected at least two numbers after the ':'"));
+        }
+        Ok(Self{left,right})
+    }
+    pub fn target(&self)->Solution{
+        self.left
+    }
+    pub fn numbers(&self)->&[Solution]{
+        &self.right
+    }
+    /// The operators to put between the numbers, from left to right, to get the target. `None` if no mix of
+    /// `operators` does it. Searches backwards from the target, so most dead ends are found after a step or two.
+    pub fn find_operators<'a>(&self, operators: &[&'a dyn Operator])->Option<Vec<&'a dyn Operator>>{
+        let mut found = Vec::with_capacity(self.right.len()-1);
+        Self::search_back(self.left, &self.right, operators, &mut found).then_some(found)
+    }
+    pub fn is_solvable(&self, operators: &[&dyn Operator])->bool{
+        self.find_operators(operators).is_some()
+    }
+    /// Whether `numbers` can make `target`. On success `found` holds the operators used, in order.
+    fn search_back<'a>(target: Solution, numbers: &[Solution], operators: &[&'a dyn Operator], found: &mut Vec<&'a dyn Operator>)->bool{
+        let Some((&last, rest)) = numbers.split_last() else{
+            return false;
+        };
+        let Some((&first, middle)) = rest.split_first() else{
+            return target == last;
+        };
+        for &operator in operators{
+            let solved = match operator.undo(target, last){
+                Some(Some(left)) => Self::search_back(left, rest, operators, found),
+                Some(None) => false,
+                None => Self::search_forward(first, middle, operators, &|value| operator.apply(value, last) == Some(target), found)
+            };
+            if solved{
+                found.push(operator);
+                return true;
+            }
+        }
+        false
+    }
+    /// Whether some mix of `operators` applied to `value` and `numbers` ends up `is_goal`. Fills `found` like `search_back`.
+    fn search_forward<'a>(value: Solution, numbers: &[Solution], operators: &[&'a dyn Operator], is_goal: &dyn Fn(Solution)->bool, found: &mut Vec<&'a dyn Operator>)->bool{
+        let Some((&next, rest)) = numbers.split_first() else{
+            return is_goal(value);
+        };
+        let depth = found.len();
+        for &operator in operators{
+            let Some(result) = operator.apply(value, next) else{
+                continue;
+            };
+            found.push(operator);
+            if Self::search_forward(result, rest, operators, is_goal, found){
+                return true;
+            }
+            found.truncate(depth);
+        }
+        false
+    }
+}
+
+fn generic_solve(eqs: &[Equation], operators: &[&dyn Operator])->Solution{
+    eqs.iter().filter(|equation| equation.is_solvable(operators))
+        .map(Equation::target)
+        .sum()
+}
+pub fn solve1(eqs: &[Equation],)->Solution{
+    generic_solve(eqs, &[&Add, &Multiply])
+}
+pub fn solve2(eqs: &[Equation],)->Solution{
+    generic_solve(eqs, &[&Add, &Multiply, &Concatenate])
+}
+pub fn make_equations(s:String)->Result<Vec<Equation>,ParseError>{
+    let input = Input::new(&s);
+    input.lines().map(|line| Equation::new(input.text(), line)).collect()
+}
+
+pub struct Day7;
+impl solution::Solution for Day7{
+    type Input = Vec<Equation>;
+    type Answer1 = Solution;
+    type Answer2 = Solution;
+    fn parse(input: &str)->Result<Self::Input,ParseError>{
+        make_equations(input.to_string())
+    }
+    fn part1(input: &Self::Input)->Solution{
+        solve1(input)
+    }
+    fn part2(input: &Self::Input)->Solution{
+        solve2(input)
+    }
+}
+   
+
+#[cfg(test)]
+mod tests{
+
+    use std::fs::read_to_string;
+
+    use support::solution::Solution as _;
+
+    use super::*;
+    #[test]
+    fn solve_test1(){
+        let file_name = "TestData1.txt";
+        let s = read_to_string(file_name).unwrap();
+        let eqs = make_equations(s).unwrap();
+        let answer = solve1(&eqs);
+        let expected = 3749;
+        assert_eq!(answer,expected)
+    }
+
+    #[test]
+    fn solve_minimal_case(){
+        let s = "190: 10 19".to_string();
+        let eqs = make_equations(s).unwrap();
+        let answer = solve1(&eqs);
+        let expected = 190;
+        assert_eq!(answer,expected)
+    }
+    #[test]
+    fn through_solution_trait(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let data = Day7::parse(&s).unwrap();
+        assert_eq!(Day7::part1(&data),3749);
+        assert_eq!(Day7::part2(&data),11387);
+    }
+    /// The operators `find_operators` picks, as text.
+    fn symbols(equation: &Equation, operators: &[&dyn Operator])->Option<String>{
+        equation.find_operators(operators)
+            .map(|found| found.iter().map(|operator| operator.symbol()).collect::<Vec<&str>>().join(" "))
+    }
+    #[test]
+    fn finds_the_operators(){
+        let eqs = make_equations("190: 10 19\n3267: 81 40 27\n156: 15 6\n7290: 6 8 6 15\n83: 17 5".to_string()).unwrap();
+        let all: [&dyn Operator; 3] = [&Add, &Multiply, &Concatenate];
+        assert_eq!(symbols(&eqs[0], &all).as_deref(), Some("*"));
+        assert_eq!(symbols(&eqs[1], &all).as_deref(), Some("* +"));
+        assert_eq!(symbols(&eqs[2], &all[..2]), None);
+        assert_eq!(symbols(&eqs[2], &all).as_deref(), Some("||"));
+        assert_eq!(symbols(&eqs[3], &all).as_deref(), Some("* || *"));
+        assert_eq!(symbols(&eqs[4], &all), None);
+    }
+    #[test]
+    fn undo_matches_apply(){
+        let all: [&dyn Operator; 3] = [&Add, &Multiply, &Concatenate];
+        for operator in all{
+            for left in [0, 1, 7, 10, 99, 123]{
+                for right in [1, 5, 10, 42, 100]{
+                    let result = operator.apply(left, right).unwrap();
+                    assert_eq!(operator.undo(result, right), Some(Some(left)), "{left} {} {right}", operator.symbol());
+                }
+            }
+        }
+        assert_eq!(Concatenate.apply(12, 0), Some(120));
+        assert_eq!(Concatenate.undo(125, 0), Some(None));
+        assert_eq!(Multiply.undo(0, 0), None);
+    }
+    #[test]
+    fn works_with_operators_that_can_not_be_undone(){
+        struct Max;
+        impl Operator for Max{
+            fn symbol(&self)->&str{
+                "max"
+            }
+            fn apply(&self, left: Solution, right: Solution)->Option<Solution>{
+                Some(left.max(right))
+            }
+        }
+        let eqs = make_equations("18: 2 9 9\n5: 3 5\n4: 3 5".to_string()).unwrap();
+        let operators: [&dyn Operator; 3] = [&Add, &Multiply, &Max];
+        let found = eqs.iter().map(|equation| symbols(equation, &operators)).collect::<Vec<_>>();
+        assert_eq!(found, [Some("max +".to_string()), Some("max".to_string()), None]);
+        // 0 can not be undone by multiplication either.
+        let eqs = make_equations("0: 4 7 0".to_string()).unwrap();
+        assert_eq!(eqs[0].find_operators(&[&Add, &Multiply]).map(|found| found.len()), Some(2));
+    }
+    #[test]
+    fn reports_bad_equations(){
+        let e = make_equations("190: 10 19\n3267 81 40 27".to_string()).unwrap_err();
+        assert_eq!((e.line(), e.column()), (2, 1));
+        let e = make_equations("190: 10 19\n3267: 81 4o 27".to_string()).unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 10, "4o"));
+        let e = make_equations("190: 10".to_string()).unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (1, 6, "10"));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,317 @@
+use std::{collections::{BTreeMap, BTreeSet}, ops::RangeInclusive};
+
+use support::{input::Input, parse::ParseError};
+
+type Solution = i32;
+type PointData = i64;
+type Data = Vec<Antenna>;
+trait DistanceTo{
+    fn vector_to(&self,other: &Self)-> [PointData;2];
+}
+#[derive(Clone,Copy,PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct PointBuilder{
+    ymax:PointData,
+    xmax:PointData,
+}
+impl PointBuilder{
+    fn new(ymax:usize, xmax:usize)->Self{
+        Self { xmax: xmax as PointData, ymax: ymax as PointData }
+    }
+    fn new_point(&self, y:PointData, x:PointData)->Option<Point>{
+        if 0 <= y && y <= self.ymax && 0 <=x && x <= self.xmax {
+            Some(Point { x, y })
+        }else{
+            None
+        }
+    }
+    fn new_with_vector(&self, point: &Point, [dy,dx]:&[PointData;2])->Option<Point>{
+        self.new_point(point.y + dy, point.x+dx)
+    }
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Point{
+    y:PointData,
+    x:PointData,
+}
+impl Point{
+    pub fn y(&self)->PointData{
+        self.y
+    }
+    pub fn x(&self)->PointData{
+        self.x
+    }
+}
+impl DistanceTo for Point{
+    fn vector_to(&self,other: &Self)-> [PointData;2] {
+        [self.y-other.y, self.x-other.x]
+    }
+}
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Frequency{
+    None,
+    Freq(char)
+}
+impl Frequency{
+    fn new(c:char)->Option<Self>{
+        let f: Frequency = c.into();
+        match f{
+            Frequency::None => None,
+            Frequency::Freq(_) => Some(f),
+        }
+    }
+}
+impl From<Frequency> for char{
+    fn from(value: Frequency) -> Self {
+        match value{
+            Frequency::None => '.',
+            Frequency::Freq(c) => c,
+        }
+    }
+}
+impl From<char> for Frequency{
+    fn from(value: char) -> Self {
+        match value{
+            ' ' | '.' => Self::None,
+            c=>Self::Freq(c)
+
+        }
+    }
+}
+#[derive(Clone,Copy,PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct Antenna{
+    point: Point,
+    frequency: Frequency
+}
+impl Antenna{
+    fn new(pos: Option<Point>, frequency: Option<Frequency>)->Option<Self>{
+        match (pos,frequency){
+            (Some(p), Some(f)) => Some(Self { point: p, frequency:f }),
+            _ => None
+        }
+        
+    }
+    fn is_same_frequency_as(&self,other:&Self)->bool{
+        self.frequency == other.frequency
+    }
+}
+impl DistanceTo for Antenna{
+    fn vector_to(&self,other: &Self)-> [PointData;2] {
+        self.point.vector_to(&other.point)
+    }
+}
+pub fn get_data(s:&str)->Result<(Data, PointBuilder),ParseError>{
+    let chars = Input::new(s).grid(|c| (c == '.' || c.is_ascii_alphanumeric()).then_some(c), "'.' or an antenna (a letter or digit)")?;
+    let builder = PointBuilder::new(chars.len()-1, chars[0].len()-1);
+    let data: Data = chars.into_iter().enumerate().flat_map(|(y,row)| row.into_iter().enumerate().filter_map(move |(x, c)| {
+        let point = builder.new_point(y as PointData ,x as PointData);
+        let freqency = Frequency::new(c);
+        Antenna::new(point, freqency)
+    } )).collect();
+    Ok((data, builder))
+}
+
+/// Which points in line with a pair of antennas are antinodes.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Harmonics{
+    /// Only where one antenna is exactly twice as far away as the other.
+    Double,
+    /// Every grid point in line with the pair, the antennas and the points between them included.
+    All,
+    /// The points this many grid points beyond either antenna, walking away from the other one. 0 is the antenna itself,
+    /// and negative steps walk towards the other antenna instead.
+    Steps(RangeInclusive<PointData>)
+}
+
+fn gcd(a: PointData, b: PointData)->PointData{
+    match b{
+        0 => a.abs(),
+        _ => gcd(b, a % b)
+    }
+}
+
+/// The antinodes of one pair of antennas inside the grid. Works in steps of the pair vector divided by
+/// the gcd of its parts, so every grid point on the line is a whole number of steps away from `b`.
+fn pair_antinodes(a: &Point, b: &Point, builder: &PointBuilder, harmonics: &Harmonics)->Vec<Point>{
+    let [dy,dx] = a.vector_to(b);
+    let steps_to_a = gcd(dy, dx);
+    if steps_to_a == 0{
+        return vec![];
+    }
+    let step = [dy/steps_to_a, dx/steps_to_a];
+    let along = |k: PointData| builder.new_with_vector(b, &[k*step[0], k*step[1]]);
+    // The points on a line inside a rectangle are all next to each other, so both walks can stop at the edge.
+    // That also keeps `k` within the size of the grid, whatever range of steps is asked for.
+    let line = (0..).map_while(|k| Some((-k, along(-k)?)))
+        .chain((1..).map_while(|k| Some((k, along(k)?))));
+    match harmonics{
+        Harmonics::Double => {
+            let mut steps = vec![-steps_to_a, 2*steps_to_a];
+            if steps_to_a % 3 == 0{
+                steps.extend([steps_to_a/3, 2*steps_to_a/3]);
+            }
+            steps.into_iter().filter_map(along).collect()
+        },
+        Harmonics::All => line.map(|(_, p)| p).collect(),
+        // `k` steps from `b` towards `a` is `-k` steps beyond `b`, and `k - steps_to_a` steps beyond `a`.
+        Harmonics::Steps(range) => line
+            .filter(|(k, _)| range.contains(&-k) || range.contains(&(k - steps_to_a)))
+            .map(|(_, p)| p)
+            .collect()
+    }
+}
+
+/// Every antinode inside the grid, per frequency.
+pub fn antinodes(data: &Data, builder: &PointBuilder, harmonics: &Harmonics)->BTreeMap<Frequency, BTreeSet<Point>>{
+    let mut found: BTreeMap<Frequency, BTreeSet<Point>> = BTreeMap::new();
+    for (a_idx, a) in data.iter().enumerate(){
+        for b in data.iter().skip(a_idx+1){
+            if !a.is_same_frequency_as(b){
+                continue;
+            }
+            found.entry(a.frequency).or_default().extend(pair_antinodes(&a.point, &b.point, builder, harmonics));
+        }
+    }
+    found
+}
+
+/// The number of grid points that are an antinode for at least one frequency.
+fn count_antinodes(data: &Data, builder: &PointBuilder, harmonics: &Harmonics)->Solution{
+    antinodes(data, builder, harmonics).into_values().flatten().collect::<BTreeSet<Point>>().len() as Solution
+}
+pub fn solve1(data: &Data, builder: &PointBuilder)->Solution{
+    count_antinodes(data, builder, &Harmonics::Double)
+}
+pub fn solve2(data: &Data, builder: &PointBuilder)->Solution{
+    count_antinodes(data, builder, &Harmonics::All)
+}
+
+#[cfg(test)]
+mod tests{
+
+    use std::fs::read_to_string;
+
+    use super::*;
+    #[test]
+    fn solve_test1(){
+        let file_name = "TestData1.txt";
+        let s = read_to_string(file_name).unwrap();
+        let (data, builder) = get_data(&s).unwrap();
+        let answer = solve1(&data, &builder);
+        let expected = 14;
+        assert_eq!(answer,expected)
+    }
+    #[test]
+    fn solve_test2(){
+        let file_name = "TestData1.txt";
+        let s = read_to_string(file_name).unwrap();
+        let (data, builder) = get_data(&s).unwrap();
+        let answer = solve2(&data, &builder);
+        let expected = 34;
+        assert_eq!(answer,expected)
+    }
+    #[test]
+    fn can_detect_antis1(){
+        let s = "...\n.A.\n..A"; // Only valid spot for an anti is (0,0);
+        let (data, builder) = get_data(s).unwrap();
+        let x = solve1(&data, &builder);
+        assert_eq!(x,1);
+        let found = antinodes(&data, &builder, &Harmonics::Double);
+        assert_eq!(found[&Frequency::Freq('A')], BTreeSet::from([Point{y:0,x:0}]));
+    }
+    #[test]
+    fn can_detect_antis2(){
+        let s =".......\n...0...\n0......"; // Only valid spot for an anti is (0,6);
+        let (data, builder) = get_data(s).unwrap();
+        let x = solve1(&data, &builder);
+        assert_eq!(x,1);
+        let found = antinodes(&data, &builder, &Harmonics::Double);
+        assert_eq!(found[&Frequency::Freq('0')], BTreeSet::from([Point{y:0,x:6}]));
+    }
+
+    #[test]
+    fn handles_aligned_antennas(){
+        let (data, builder) = get_data("A.A......\n.........").unwrap();
+        assert_eq!(solve1(&data, &builder), 1);
+        assert_eq!(solve2(&data, &builder), 9);
+        let (data, builder) = get_data("A..\n...\nA..\n...").unwrap();
+        assert_eq!(solve2(&data, &builder), 4);
+    }
+    #[test]
+    fn walks_in_reduced_steps(){
+        // (0,0) and (2,4) are two steps of (1,2) apart, so (1,2) is on the line too.
+        let (data, builder) = get_data("a......\n.......\n....a..").unwrap();
+        let all = antinodes(&data, &builder, &Harmonics::All);
+        let expected = [(0,0),(1,2),(2,4)].map(|(y,x)| Point{y,x});
+        assert_eq!(all[&Frequency::Freq('a')], BTreeSet::from(expected));
+        // Three steps of (1,1): the points a third of the way along count for part 1.
+        let (data, builder) = get_data("b...\n....\n....\n...b").unwrap();
+        let double = antinodes(&data, &builder, &Harmonics::Double);
+        assert_eq!(double[&Frequency::Freq('b')], BTreeSet::from([Point{y:1,x:1}, Point{y:2,x:2}]));
+    }
+    #[test]
+    fn groups_by_frequency(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let (data, builder) = get_data(&s).unwrap();
+        let found = antinodes(&data, &builder, &Harmonics::Double);
+        assert_eq!(found.keys().copied().collect::<Vec<_>>(), [Frequency::Freq('0'), Frequency::Freq('A')]);
+        assert_eq!(found.values().map(BTreeSet::len).collect::<Vec<_>>(), [10, 5]);
+        let antennas = antinodes(&data, &builder, &Harmonics::Steps(0..=0));
+        assert_eq!(antennas.values().map(BTreeSet::len).sum::<usize>(), data.len());
+        let everything = antinodes(&data, &builder, &Harmonics::Steps(0..=PointData::MAX));
+        assert_eq!(everything.into_values().flatten().collect::<BTreeSet<_>>().len(), 34);
+    }
+    #[test]
+    fn steps_towards_the_other_antenna(){
+        // Four steps of (0,1) from one antenna to the other.
+        let (data, builder) = get_data("..a...a..").unwrap();
+        let points = |harmonics: Harmonics| antinodes(&data, &builder, &harmonics)[&Frequency::Freq('a')].iter().map(Point::x).collect::<Vec<_>>();
+        assert_eq!(points(Harmonics::Steps(-1..=0)), [2, 3, 5, 6]);
+        assert_eq!(points(Harmonics::Steps(-3..=-2)), [3, 4, 5]);
+        // Walking far enough towards the other antenna passes it, and the points beyond it count.
+        assert_eq!(points(Harmonics::Steps(-100..=-5)), [0, 1, 7, 8]);
+        assert_eq!(points(Harmonics::Steps(-3..=2)), (0..9).collect::<Vec<_>>());
+        assert_eq!(points(Harmonics::Steps(PointData::MIN..=PointData::MAX)), points(Harmonics::All));
+        assert!(points(Harmonics::Steps(PointData::MAX-1..=PointData::MAX)).is_empty());
+    }
+    #[test]
+    fn reports_bad_maps(){
+        let e = get_data("...\n.A#\n..A").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 3, "#"));
+        let e = get_data("...\n.A\n..A").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 1, ".A"));
+        assert_eq!(get_data("").unwrap_err().line(), 0);
+    }
+
+    mod comparisons{
+        use super::*;
+        #[test]
+        fn detect_same_antenna(){
+            let a = Antenna::new(Some(Point{y:2,x:2}), Some(Frequency::Freq('a'))).unwrap();
+            let b = Antenna::new(Some(Point{y:2,x:2}), Some(Frequency::Freq('a'))).unwrap();
+            assert_eq!(a,b)
+        }
+        
+        #[test]
+        fn detect_different_antenna(){
+            let a = Antenna::new(Some(Point{y:2,x:2}), Some(Frequency::Freq('a'))).unwrap();
+            let b = Antenna::new(Some(Point{y:2,x:2}), Some(Frequency::Freq('b'))).unwrap();
+            let c = Antenna::new(Some(Point{y:1,x:2}), Some(Frequency::Freq('a'))).unwrap();
+            assert_ne!(a,b);
+            assert_ne!(a,c);
+            assert_ne!(b,c);
+        }
+        #[test]
+        fn detect_same_freq(){
+            let a = Frequency::Freq('a');
+            let b = Frequency::Freq('a');
+            assert_eq!(a,b)
+        }
+        #[test]
+        fn detect_different_freq(){
+            let a = Frequency::Freq('a');
+            let b = Frequency::Freq('b');
+            assert_ne!(a,b)
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,252 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use support::{input::Input, parse::ParseError};
+
+type Solution = usize;
+#[derive(Clone,Copy,PartialEq, Eq, PartialOrd, Ord,Debug)]
+pub struct Point{
+    count: usize,
+    id: usize,
+}
+impl Point{
+    fn new(c:char, idx:usize)->Option<Self>{
+        let count = c.to_digit(10)? as usize;
+        Some(Self{ count,id: idx})
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum PointType{
+    Space(Point),
+    Real(Point)
+}
+impl PointType{
+    #[allow(dead_code)]
+    fn is_space(&self)->bool{
+        match self{
+            Self::Space(_) => true,
+            Self::Real(_) => false,
+        }
+    }
+    fn count(&self)->usize{
+        let point = match self{
+            Self::Space(point) => point,
+            Self::Real(point) => point,
+        };
+        point.count
+    }
+}
+impl Display for PointType{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            PointType::Space(point) => {
+                let s = (0..point.count).map(|_| ".").collect::<String>();
+                write!(f,"{s}")
+            },
+            PointType::Real(point) => {
+                let s = (0..point.count).map(|_| format!("{}",point.id)).collect::<String>();
+                write!(f,"{s}")
+            },
+        }
+    }
+}
+pub fn get_data(s:&str)->Result<Vec<PointType>,ParseError>{
+    let input = Input::new(s);
+    input.lines().flat_map(|line| line.char_indices().enumerate().map(move |(idx,(col,c))|  {
+        let is_space = idx%2 == 1;
+        let Some(block) = Point::new(c,idx/2) else {
+            return Err(input.error(&line[col..col+c.len_utf8()], "expected a digit"));
+        };
+        if is_space{
+            Ok(PointType::Space(block))
+        }else{
+            Ok(PointType::Real(block))
+        }
+    })).collect()
+}
+
+/// A run of blocks on the disk, holding part of file `file` or free if that is `None`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Span{
+    pub start: usize,
+    pub len: usize,
+    pub file: Option<usize>
+}
+
+/// The disk as a list of spans, in order and without gaps between them.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Disk{
+    spans: Vec<Span>
+}
+impl Disk{
+    pub fn new(data:&[PointType])->Self{
+        let mut start = 0;
+        let spans = data.iter().map(|point|{
+            let file = match point{
+                PointType::Space(_) => None,
+                PointType::Real(block) => Some(block.id)
+            };
+            let span = Span{start, len: point.count(), file};
+            start += span.len;
+            span
+        }).filter(|span| span.len > 0).collect();
+        Self{spans}
+    }
+    /// Builds the disk back up from the file spans, filling what is left with free space.
+    fn from_files(len: usize, mut files: Vec<Span>)->Self{
+        files.sort_by_key(|span| span.start);
+        let mut spans = Vec::with_capacity(files.len()*2);
+        let mut end = 0;
+        for file in files{
+            if file.start > end{
+                spans.push(Span{start: end, len: file.start - end, file: None});
+            }
+            end = file.start + file.len;
+            match spans.last_mut(){
+                Some(last) if last.file == file.file && last.start + last.len == file.start => last.len += file.len,
+                _ => spans.push(file)
+            }
+        }
+        if len > end{
+            spans.push(Span{start: end, len: len - end, file: None});
+        }
+        Self{spans}
+    }
+    pub fn spans(&self)->&[Span]{
+        &self.spans
+    }
+    pub fn len(&self)->usize{
+        self.spans.last().map_or(0, |span| span.start + span.len)
+    }
+    pub fn is_empty(&self)->bool{
+        self.len() == 0
+    }
+    fn files(&self)->impl Iterator<Item = Span> + '_{
+        self.spans.iter().copied().filter(|span| span.file.is_some())
+    }
+    /// Moves single blocks from the end of the disk into the leftmost free block, until there are no gaps left.
+    pub fn compact_blocks(&mut self){
+        let mut queue = self.spans.iter()
+            .flat_map(|span| std::iter::repeat_n(span.file, span.len))
+            .collect::<VecDeque<Option<usize>>>();
+        let mut group = Vec::new();
+        'outer: while let Some(element) = queue.pop_front(){
+            if let Some(id) = element{
+                group.push(id);
+            }else{
+                loop{
+                    match queue.pop_back(){
+                        None => break 'outer,
+                        Some(None) => continue,
+                        Some(Some(id)) => {
+                            group.push(id);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+        let files = group.into_iter().enumerate().map(|(start, id)| Span{start, len: 1, file: Some(id)}).collect();
+        *self = Self::from_files(self.len(), files);
+    }
+    /// Moves every file, highest id first, into the leftmost gap it fits in. Files that fit nowhere to their left stay put.
+    pub fn compact_files(&mut self){
+        let mut files = self.files().collect::<Vec<Span>>();
+        let mut free = self.spans.iter().copied().filter(|span| span.file.is_none()).collect::<Vec<Span>>();
+        files.sort_by_key(|span| std::cmp::Reverse((span.file, span.start)));
+        for file in files.iter_mut(){
+            let gap = free.iter_mut()
+                .take_while(|gap| gap.start < file.start)
+                .find(|gap| gap.len >= file.len);
+            if let Some(gap) = gap{
+                file.start = gap.start;
+                gap.start += file.len;
+                gap.len -= file.len;
+            }
+        }
+        *self = Self::from_files(self.len(), files);
+    }
+    pub fn checksum(&self)->Solution{
+        self.files()
+            .map(|span| (span.start..span.start+span.len).sum::<usize>() * span.file.unwrap_or_default())
+            .sum()
+    }
+}
+impl Display for Disk{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for span in &self.spans{
+            let point = Point{count: span.len, id: span.file.unwrap_or_default()};
+            match span.file{
+                Some(_) => write!(f,"{}",PointType::Real(point))?,
+                None => write!(f,"{}",PointType::Space(point))?
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn solve1(data:&[PointType])->Solution{
+    let mut disk = Disk::new(data);
+    disk.compact_blocks();
+    disk.checksum()
+}
+
+pub fn solve2(data:&[PointType])->Solution{
+    let mut disk = Disk::new(data);
+    disk.compact_files();
+    disk.checksum()
+}
+
+#[cfg(test)]
+mod tests{
+    use std::fs::read_to_string;
+
+    use super::*;
+    #[test]
+    fn solve_test1(){
+        let file_name = "TestData1.txt";
+        let expected = 1928;
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution1 = solve1(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn solve_test2(){
+        let file_name = "TestData1.txt";
+        let expected = 2858;
+        let s = read_to_string(file_name).unwrap();
+        let data = get_data(&s).unwrap();
+        let solution1 = solve2(&data);
+        assert_eq!(solution1,expected)
+    }
+    #[test]
+    fn compacts_the_example(){
+        let s = read_to_string("TestData1.txt").unwrap();
+        let disk = Disk::new(&get_data(&s).unwrap());
+        assert_eq!(disk.to_string(), "00...111...2...333.44.5555.6666.777.888899");
+        let mut blocks = disk.clone();
+        blocks.compact_blocks();
+        assert_eq!(blocks.to_string(), "0099811188827773336446555566..............");
+        let mut files = disk.clone();
+        files.compact_files();
+        assert_eq!(files.to_string(), "00992111777.44.333....5555.6666.....8888..");
+        assert_eq!(files.len(), disk.len());
+    }
+    #[test]
+    fn compacts_a_small_disk(){
+        let mut disk = Disk::new(&get_data("12345\n").unwrap());
+        assert_eq!(disk.to_string(), "0..111....22222");
+        disk.compact_files();
+        assert_eq!(disk.to_string(), "0..111....22222");
+        disk.compact_blocks();
+        assert_eq!(disk.to_string(), "022111222......");
+        assert_eq!(disk.spans()[1], Span{start: 1, len: 2, file: Some(2)});
+    }
+    #[test]
+    fn reports_bad_digits(){
+        assert_eq!(get_data("12345\n").unwrap().len(), 5);
+        let e = get_data("123x5\n").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (1, 4, "x"));
+    }
+}
\ No newline at end of file
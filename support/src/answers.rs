@@ -0,0 +1,164 @@
+use std::{collections::HashMap, fmt::Display, sync::OnceLock};
+
+use serde::Deserialize;
+
+/// The registry checked in at the root of the workspace.
+const ANSWERS_TOML: &str = include_str!("../../answers.toml");
+
+/// An answer as written in `answers.toml`; most are numbers, but some days answer with text.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum Expected{
+    Number(i64),
+    Text(String)
+}
+impl Display for Expected{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self{
+            Self::Number(n) => write!(f,"{n}"),
+            Self::Text(s) => write!(f,"{s}"),
+        }
+    }
+}
+
+/// Everything we know about one part of one day.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Known{
+    pub answer: Option<Expected>,
+    pub too_low: Option<i64>,
+    pub too_high: Option<i64>,
+    #[serde(default)]
+    pub wrong: Vec<Expected>
+}
+impl Known{
+    pub fn check(&self, answer: &str)->Verdict{
+        if let Some(expected) = &self.answer{
+            return if expected.to_string() == answer { Verdict::Correct } else { Verdict::Wrong };
+        }
+        if self.wrong.iter().any(|wrong| wrong.to_string() == answer){
+            return Verdict::Wrong;
+        }
+        if let Ok(n) = answer.parse::<i128>(){
+            if let Some(too_low) = self.too_low && n <= too_low as i128{
+                return Verdict::TooLow;
+            }
+            if let Some(too_high) = self.too_high && n >= too_high as i128{
+                return Verdict::TooHigh;
+            }
+        }
+        Verdict::Unknown
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers{
+    part1: Option<Known>,
+    part2: Option<Known>
+}
+
+/// What the registry has to say about a computed answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict{
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    Unknown
+}
+impl Verdict{
+    /// Whether the answer is known to be wrong in any way.
+    pub fn is_wrong(&self)->bool{
+        matches!(self, Self::Wrong | Self::TooHigh | Self::TooLow)
+    }
+}
+impl Display for Verdict{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self{
+            Self::Correct => "correct",
+            Self::Wrong => "wrong",
+            Self::TooHigh => "too high",
+            Self::TooLow => "too low",
+            Self::Unknown => "unknown",
+        };
+        write!(f,"{s}")
+    }
+}
+
+/// Known answers, keyed by day (`day5`) and part.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Answers{
+    days: HashMap<String,DayAnswers>
+}
+impl Answers{
+    pub fn parse(s: &str)->Result<Self,toml::de::Error>{
+        toml::from_str(s)
+    }
+    /// The answers from the workspace's `answers.toml`.
+    pub fn registry()->&'static Self{
+        static REGISTRY: OnceLock<Answers> = OnceLock::new();
+        REGISTRY.get_or_init(|| Self::parse(ANSWERS_TOML).expect("answers.toml is valid"))
+    }
+    pub fn get(&self, day: u8, part: u8)->Option<&Known>{
+        let day_answers = self.days.get(&format!("day{day}"))?;
+        match part{
+            1 => day_answers.part1.as_ref(),
+            2 => day_answers.part2.as_ref(),
+            _ => None
+        }
+    }
+    pub fn check(&self, day: u8, part: u8, answer: impl Display)->Verdict{
+        match self.get(day, part){
+            Some(known) => known.check(&answer.to_string()),
+            None => Verdict::Unknown
+        }
+    }
+}
+
+/// Checks an answer against the workspace's `answers.toml`.
+pub fn check(day: u8, part: u8, answer: impl Display)->Verdict{
+    Answers::registry().check(day, part, answer)
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    const ANSWERS: &str = "
+        [day1]
+        part1 = { answer = 42 }
+        part2 = { too_low = 10, too_high = 20, wrong = [15, \"abc\"] }
+        [day2]
+        part1 = { answer = \"HELLO\" }
+    ";
+
+    #[test]
+    fn exact_answers(){
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 1, 42), Verdict::Correct);
+        assert_eq!(answers.check(1, 1, 41), Verdict::Wrong);
+        assert_eq!(answers.check(2, 1, "HELLO"), Verdict::Correct);
+        assert_eq!(answers.check(2, 1, "HELO"), Verdict::Wrong);
+    }
+    #[test]
+    fn bounds_and_known_wrong_answers(){
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, 2, 10), Verdict::TooLow);
+        assert_eq!(answers.check(1, 2, 20), Verdict::TooHigh);
+        assert_eq!(answers.check(1, 2, 15), Verdict::Wrong);
+        assert_eq!(answers.check(1, 2, "abc"), Verdict::Wrong);
+        assert_eq!(answers.check(1, 2, 11), Verdict::Unknown);
+    }
+    #[test]
+    fn missing_entries_are_unknown(){
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(2, 2, 1), Verdict::Unknown);
+        assert_eq!(answers.check(3, 1, 1), Verdict::Unknown);
+    }
+    #[test]
+    fn registry_parses(){
+        assert_eq!(check(5, 1, 4905), Verdict::Correct);
+    }
+}
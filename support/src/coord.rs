@@ -0,0 +1,53 @@
+use std::{fmt::{Debug, Display}, hash::Hash, ops::{Add, Div, Mul, Rem, Sub}};
+
+/// The integer types a grid can be indexed with.
+///
+/// Bundles the arithmetic and conversions [`crate::matrix::Matrix`] needs, so code using it only has to ask for `I: Coord`.
+/// Conversions to and from `usize` fail for values that do not fit, like negative coordinates.
+pub trait Coord: Copy + Eq + Ord + Hash + Debug + Display + Default
+    + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Rem<Output = Self>
+    + TryFrom<usize> + TryInto<usize>{
+    const ZERO: Self;
+    const ONE: Self;
+    fn from_usize(value: usize)->Option<Self>{
+        Self::try_from(value).ok()
+    }
+    fn to_usize(self)->Option<usize>{
+        self.try_into().ok()
+    }
+    fn to_i128(self)->Option<i128>;
+    fn from_i128(value: i128)->Option<Self>;
+    /// `self + delta`, or `None` if that does not fit.
+    fn add_signed(self, delta: i64)->Option<Self>{
+        Self::from_i128(self.to_i128()?.checked_add(delta.into())?)
+    }
+}
+macro_rules! impl_coord{
+    ($($int:ty),*) => {
+        $(impl Coord for $int{
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+            fn to_i128(self)->Option<i128>{
+                i128::try_from(self).ok()
+            }
+            fn from_i128(value: i128)->Option<Self>{
+                Self::try_from(value).ok()
+            }
+        })*
+    };
+}
+impl_coord!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn conversions_fail_outside_usize(){
+        assert_eq!(i32::from_usize(7), Some(7));
+        assert_eq!(u8::from_usize(300), None);
+        assert_eq!((-1i64).to_usize(), None);
+        assert_eq!(i128::ONE.to_usize(), Some(1));
+        assert_eq!((0usize.add_signed(-1), 0i8.add_signed(-1), u128::MAX.add_signed(0)), (None, Some(-1), None));
+    }
+}
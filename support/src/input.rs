@@ -0,0 +1,75 @@
+use crate::parse::{self, ParseError};
+
+/// Puzzle input with the differences between editors and platforms smoothed over.
+///
+/// A leading byte order mark and any trailing blank lines are dropped, and every line comes out
+/// without its `\r` or trailing spaces. Everything handed out is a slice of the original text,
+/// so it can still be used to point at the input in a [`ParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Input<'a>{
+    text: &'a str
+}
+impl<'a> Input<'a>{
+    pub fn new(raw: &'a str)->Self{
+        let text = raw.strip_prefix('\u{feff}').unwrap_or(raw).trim_end();
+        Self{text}
+    }
+    /// The normalised text, which error positions are counted from.
+    pub fn text(&self)->&'a str{
+        self.text
+    }
+    pub fn is_empty(&self)->bool{
+        self.text.is_empty()
+    }
+    /// Every line, without line endings or trailing whitespace.
+    pub fn lines(&self)->impl Iterator<Item = &'a str> + 'a{
+        self.text.lines().map(str::trim_end)
+    }
+    /// Groups of lines separated by one or more blank lines.
+    pub fn blocks(&self)->impl Iterator<Item = Vec<&'a str>> + 'a{
+        let mut lines = self.lines().peekable();
+        std::iter::from_fn(move ||{
+            while lines.next_if(|line| line.is_empty()).is_some(){}
+            let block: Vec<&str> = std::iter::from_fn(|| lines.next_if(|line| !line.is_empty())).collect();
+            if block.is_empty() { None } else { Some(block) }
+        })
+    }
+    /// Reads a rectangular grid, turning every character into a tile with `tile`.
+    /// `expected` describes the valid characters for the error message when `tile` gives back `None`.
+    pub fn grid<T>(&self, tile: impl Fn(char)->Option<T>, expected: &str)->Result<Vec<Vec<T>>,ParseError>{
+        parse::grid(self.text, &self.lines().collect::<Vec<_>>(), tile, expected)
+    }
+    /// A [`ParseError`] pointing at `text`, which has to be a slice of this input.
+    pub fn error(&self, text: &str, message: impl Into<String>)->ParseError{
+        ParseError::at(self.text, text, message)
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn line_endings_do_not_matter(){
+        let unix = Input::new("ab\ncd\n\nef\n");
+        let windows = Input::new("\u{feff}ab\r\ncd\r\n\r\nef\r\n\r\n");
+        assert_eq!(unix.lines().collect::<Vec<_>>(), ["ab", "cd", "", "ef"]);
+        assert_eq!(windows.lines().collect::<Vec<_>>(), ["ab", "cd", "", "ef"]);
+        assert_eq!(unix.blocks().collect::<Vec<_>>(), windows.blocks().collect::<Vec<_>>());
+    }
+    #[test]
+    fn blocks_skip_repeated_blank_lines(){
+        let input = Input::new("\nab\ncd\n\n  \n\nef\n\n");
+        assert_eq!(input.blocks().collect::<Vec<_>>(), [vec!["ab", "cd"], vec!["ef"]]);
+    }
+    #[test]
+    fn grids_must_be_rectangular(){
+        let digit = |c: char| c.to_digit(10);
+        assert_eq!(Input::new("12\r\n34\r\n").grid(digit, "a digit"), Ok(vec![vec![1,2],vec![3,4]]));
+        let e = Input::new("12\n3\n").grid(digit, "a digit").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 1, "3"));
+        let e = Input::new("\u{feff}12\n3x\n").grid(digit, "a digit").unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text(), e.message()), (2, 2, "x", "expected a digit"));
+        assert_eq!(Input::new("\n\n").grid(digit, "a digit").unwrap_err().line(), 0);
+    }
+}
@@ -1,22 +1,36 @@
+pub mod answers;
+pub mod coord;
+pub mod input;
+pub mod parse;
+pub mod regions;
+pub mod search;
+pub mod solution;
+pub mod sparse;
+
 pub mod cache{
     use std::collections::HashMap;
     use std::hash::Hash;
-    struct Cache<IdNum,CacheNum>{
+    pub struct Cache<IdNum,CacheNum>{
         cache: HashMap::<IdNum,[Option<CacheNum>;75]>
     }
+    impl <IdNum:Copy+Eq+Hash,CacheNum:Copy>Default for Cache<IdNum,CacheNum>{
+        fn default()->Self{
+            Self::new()
+        }
+    }
     impl <IdNum:Copy+Eq+Hash,CacheNum:Copy>Cache<IdNum,CacheNum>{
-        fn new()->Self{
+        pub fn new()->Self{
             let cache = HashMap::new();
             Self{cache}
         }
-        fn get(&self,key:&IdNum, iteration: &usize)->Option<CacheNum>{
+        pub fn get(&self,key:&IdNum, iteration: &usize)->Option<CacheNum>{
             if let Some(arr) = self.cache.get(key){
                 arr[*iteration]
             }else{
                 None
             }
         }
-        fn insert(&mut self, key:&IdNum, iteration: &usize, value: CacheNum)->Option<CacheNum>{
+        pub fn insert(&mut self, key:&IdNum, iteration: &usize, value: CacheNum)->Option<CacheNum>{
             if let Some(arr) = self.cache.get_mut(key){
                 if let Some(previously_existing) = arr[*iteration]{
                     Some(previously_existing)
@@ -35,11 +49,9 @@ pub mod cache{
 }
 
 pub mod direction{
-    use std::cmp::Ordering;
-
     use crate::position::Position;
 
-    #[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord, Hash)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub enum Direction{
         Up,
         Down,
@@ -85,20 +97,176 @@ pub mod direction{
             }
         }
     }
-    impl PartialEq for Direction{
-        fn eq(&self, other: &Self) -> bool {
-            match (self,other){
-                (Self::None, _) | (_, Self::None) => true,
-                (Self::Up, Self::Up) | (Self::Down, Self::Down) | (Self::Left, Self::Left) |(Self::Right, Self::Right) => true,
-                _ => false
+    impl Direction{
+        pub const ALL: [Self;5] = [Self::Up, Self::Down, Self::Left, Self::Right, Self::None];
+    }
+
+    /// Which directions to accept where any direction will do, since [`Direction`] itself only equals itself.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum DirectionPattern{
+        Any,
+        Exactly(Direction)
+    }
+    impl DirectionPattern{
+        pub fn matches(&self, direction: Direction)->bool{
+            match self{
+                Self::Any => true,
+                Self::Exactly(expected) => *expected == direction
+            }
+        }
+    }
+    impl From<Direction> for DirectionPattern{
+        /// `Direction::None` has no heading, so it accepts any direction.
+        fn from(direction: Direction)->Self{
+            match direction{
+                Direction::None => Self::Any,
+                _ => Self::Exactly(direction)
             }
         }
     }
+
+    /// The eight compass directions, clockwise from north. North is up, like [`Direction::Up`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Direction8{
+        N,
+        NE,
+        E,
+        SE,
+        S,
+        SW,
+        W,
+        NW
+    }
+    impl Direction8{
+        pub const ALL: [Self;8] = [Self::N, Self::NE, Self::E, Self::SE, Self::S, Self::SW, Self::W, Self::NW];
+        pub fn all()->impl Iterator<Item = Self>{
+            Self::ALL.into_iter()
+        }
+        pub fn as_vector(&self)->Position<i32>{
+            match self{
+                Self::N  => Position::new( 0,-1),
+                Self::NE => Position::new( 1,-1),
+                Self::E  => Position::new( 1, 0),
+                Self::SE => Position::new( 1, 1),
+                Self::S  => Position::new( 0, 1),
+                Self::SW => Position::new(-1, 1),
+                Self::W  => Position::new(-1, 0),
+                Self::NW => Position::new(-1,-1),
+            }
+        }
+        pub fn is_diagonal(&self)->bool{
+            matches!(self, Self::NE | Self::SE | Self::SW | Self::NW)
+        }
+        /// Turns clockwise by `steps` eighths of a full turn.
+        fn turned(self, steps: usize)->Self{
+            Self::ALL[(self as usize + steps) % 8]
+        }
+        pub fn rot_right45(self)->Self{
+            self.turned(1)
+        }
+        pub fn rot_left45(self)->Self{
+            self.turned(7)
+        }
+        pub fn rot_right(self)->Self{
+            self.turned(2)
+        }
+        pub fn rot_left(self)->Self{
+            self.turned(6)
+        }
+        pub fn opposite(self)->Self{
+            self.turned(4)
+        }
+    }
+    impl TryFrom<Direction> for Direction8{
+        type Error = ();
+        fn try_from(value: Direction)->Result<Self,()>{
+            match value{
+                Direction::Up => Ok(Self::N),
+                Direction::Right => Ok(Self::E),
+                Direction::Down => Ok(Self::S),
+                Direction::Left => Ok(Self::W),
+                Direction::None => Err(())
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests{
+        use std::{cmp::Ordering, collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+
+        use super::*;
+
+        fn hash_of(direction: Direction)->u64{
+            let mut hasher = DefaultHasher::new();
+            direction.hash(&mut hasher);
+            hasher.finish()
+        }
+        // `Direction` is small enough to check the laws for every pair and triple instead of sampling.
+        #[test]
+        fn eq_and_hash_agree(){
+            for a in Direction::ALL{
+                assert_eq!(a, a);
+                for b in Direction::ALL{
+                    assert_eq!(a == b, b == a);
+                    if a == b{
+                        assert_eq!(hash_of(a), hash_of(b));
+                    }
+                    assert_eq!(a == b, a.cmp(&b) == Ordering::Equal);
+                    for c in Direction::ALL{
+                        if a == b && b == c{
+                            assert_eq!(a, c);
+                        }
+                    }
+                }
+            }
+        }
+        #[test]
+        fn ord_is_a_total_order(){
+            for a in Direction::ALL{
+                for b in Direction::ALL{
+                    assert_eq!(a.cmp(&b), b.cmp(&a).reverse());
+                    assert_eq!(a.partial_cmp(&b), Some(a.cmp(&b)));
+                    for c in Direction::ALL{
+                        if a <= b && b <= c{
+                            assert!(a <= c);
+                        }
+                    }
+                }
+            }
+        }
+        #[test]
+        fn none_is_only_a_wildcard_in_patterns(){
+            assert_ne!(Direction::None, Direction::Up);
+            for direction in Direction::ALL{
+                assert!(DirectionPattern::Any.matches(direction));
+                assert!(DirectionPattern::from(Direction::None).matches(direction));
+                assert_eq!(DirectionPattern::Exactly(Direction::Left).matches(direction), direction == Direction::Left);
+                assert_eq!(DirectionPattern::from(direction).matches(Direction::Right), matches!(direction, Direction::None | Direction::Right));
+            }
+        }
+        #[test]
+        fn eight_way_rotation(){
+            assert_eq!(Direction8::all().count(), 8);
+            for direction in Direction8::all(){
+                assert_eq!(direction.rot_right45().rot_left45(), direction);
+                assert_eq!(direction.rot_right().rot_right(), direction.opposite());
+                assert_eq!(direction.opposite().as_vector(), direction.as_vector()*-1);
+                assert_eq!(direction.rot_right45().is_diagonal(), !direction.is_diagonal());
+            }
+            assert_eq!(Direction8::NW.rot_right45(), Direction8::N);
+            assert_eq!(Direction8::try_from(Direction::Left), Ok(Direction8::W));
+            assert_eq!(Direction8::try_from(Direction::Up).unwrap().as_vector(), Direction::Up.as_vector());
+            assert_eq!(Position::new(0usize,0).neighbours8().into_iter().flatten().collect::<Vec<_>>(), [Position::new(1,0), Position::new(1,1), Position::new(0,1)]);
+            assert_eq!(Position::new(0i64,0).neighbours8()[7], Some(Position::new(-1,-1)));
+        }
+    }
 }
 
 pub mod position{
     use std::{fmt::Display, ops::{Add, Mul, Sub}};
 
+    use crate::{coord::Coord, direction::Direction8};
+
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
     pub struct Position<IntType>{
@@ -111,6 +279,11 @@ pub mod position{
         }
 
     }
+    impl <IntType:Eq>Position<IntType>{
+        pub fn is_equal_to(&self, other: &Self)->bool{
+            self.x == other.x && self.y == other.y
+        }
+    }
     impl <IntType>Position<IntType>
     where IntType: Copy{
         pub fn x(&self)->IntType{
@@ -126,20 +299,35 @@ pub mod position{
      {
         fn adjusted(&self, dy:i32,dx:i32)->Option<Self>{
             match (self.y != 0.into() || dy != -1) && (self.x != 0.into() || dx != -1){
-                true => {
-                    let new_x = i32::from(self.x ) + dx;
-                    let new_y = i32::from(self.y ) + dy;
-                    debug_assert!(new_x >= 0);
-                    debug_assert!(new_y >= 0);
-                    Some(Self::new((self.x.into() + dx).into(), (self.y.into()+dy).into()))},
+                true => Some(Self::new((self.x.into() + dx).into(), (self.y.into()+dy).into())),
                 false => None,
             }
         }
         pub fn neighbours(&self)->[Option<Position<IntType>>;4]{
             [[-1,0],[1,0],[0,-1],[0,1]].into_iter().map(|[dy,dx]| self.adjusted(dy, dx)).collect::<Vec<Option<Position<IntType>>>>().try_into().unwrap()
         }
+        pub fn abs_diff(&self,other:&Self)->i32{
+            (i32::from(self.x())-i32::from(other.x())).abs() + (i32::from(self.y())-i32::from(other.y())).abs()
+        }
 
     }
+    impl <IntType: Coord>Position<IntType>{
+        /// Moves `dx` and `dy` away, or `None` if that does not fit in `IntType`.
+        pub fn offset(&self, dx: i64, dy: i64)->Option<Self>{
+            Some(Self::new(self.x.add_signed(dx)?, self.y.add_signed(dy)?))
+        }
+        /// The positions above, below, left and right of this one, in that order, with no bounds but those of `IntType`.
+        pub fn adjacent(&self)->[Option<Self>;4]{
+            [(0,-1),(0,1),(-1,0),(1,0)].map(|(dx,dy)| self.offset(dx, dy))
+        }
+        /// All eight surrounding positions, diagonals included, in the order of [`Direction8::ALL`].
+        pub fn neighbours8(&self)->[Option<Self>;8]{
+            Direction8::ALL.map(|direction|{
+                let vector = direction.as_vector();
+                self.offset(vector.x().into(), vector.y().into())
+            })
+        }
+    }
     impl <IntType>Add for Position<IntType>
         where IntType: Add<Output=IntType>{
         type Output = Self;
@@ -170,16 +358,6 @@ pub mod position{
             write!(f,"(x: {}, y: {})", self.x,self.y)
         }
     }
-    impl <IntType>TryFrom<(usize,usize)> for Position<IntType>
-    where IntType: TryFrom<usize>,
-            usize: TryFrom<IntType>{
-        type Error = <IntType as TryFrom<usize>>::Error;
-        fn try_from(value: (usize,usize)) -> Result<Self, Self::Error> {
-            let x= value.0.try_into()?;
-            let y = value.1.try_into()?;
-            Ok( Self::new(x, y ))
-        }
-    }
     impl <IntType>TryFrom<(IntType,IntType)> for Position<IntType>{
         type Error = ();
         fn try_from(value: (IntType,IntType)) -> Result<Self, Self::Error> {
@@ -193,313 +371,591 @@ pub mod position{
 
 pub mod matrix{
     use std::{collections::HashMap, fmt::Display, marker::PhantomData};
-    use crate::position::Position;
+    use crate::{coord::Coord, direction::Direction, position::Position};
 
     pub trait GetSet<KeyType,ValueType>{
         fn get(&self, key: KeyType)-> Option<ValueType>;
         fn set(&mut self, key: KeyType, value: ValueType);
     }
-    pub trait TypeToIndex<IndexType>{
-        fn to_idx(&self, input: IndexType)->Option<usize>;
-        fn from_idx(&self, idx: usize)->Option<IndexType>;
+
+    /// Where a [`Matrix`] keeps its tiles, addressed by their row-major index.
+    pub trait GridStorage<DataType>{
+        fn tile(&self, idx: usize)->Option<&DataType>;
+        /// Overwrites (or for sparse storage, adds) the tile at `idx`, which the matrix has already bounds checked.
+        fn put(&mut self, idx: usize, value: DataType);
+        /// Every stored tile with its index. Dense storage gives them in order; sparse storage in no particular order.
+        fn stored<'a>(&'a self)->impl Iterator<Item = (usize, &'a DataType)> where DataType: 'a;
+    }
+    impl <DataType>GridStorage<DataType> for Vec<DataType>{
+        fn tile(&self, idx: usize)->Option<&DataType>{
+            self.get(idx)
+        }
+        fn put(&mut self, idx: usize, value: DataType){
+            self[idx] = value;
+        }
+        fn stored<'a>(&'a self)->impl Iterator<Item = (usize, &'a DataType)> where DataType: 'a{
+            self.iter().enumerate()
+        }
+    }
+    impl <DataType>GridStorage<DataType> for HashMap<usize,DataType>{
+        fn tile(&self, idx: usize)->Option<&DataType>{
+            self.get(&idx)
+        }
+        fn put(&mut self, idx: usize, value: DataType){
+            self.insert(idx, value);
+        }
+        fn stored<'a>(&'a self)->impl Iterator<Item = (usize, &'a DataType)> where DataType: 'a{
+            self.iter().map(|(idx,val)| (*idx,val))
+        }
+    }
+
+    /// What happens at the edges of a [`Matrix`].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    pub enum Topology{
+        /// Positions off the edge are outside the matrix.
+        #[default]
+        Bounded,
+        /// The edges wrap around, so every position is valid and stands for the one it wraps to.
+        Toroidal
     }
 
-    #[derive(Clone)]
-    pub struct Matrix<DataType,IntType, StorageType, IndexType>{
-        index_type: PhantomData<IndexType>,
-        data_type: PhantomData<DataType>,
-        int_type: PhantomData<IntType>,
+    /// A `width` x `height` grid indexed by `Position<IntType>`.
+    /// Tiles live in a `Vec` by default; a `HashMap<usize,DataType>` only keeps the interesting ones.
+    #[derive(Debug, Clone)]
+    pub struct Matrix<DataType, IntType = i32, StorageType = Vec<DataType>>{
+        int_type: PhantomData<(IntType, DataType)>,
         width: usize,
         height: usize,
+        topology: Topology,
         data: StorageType,
     }
-    // First General Impl
-    impl <DataType, IntType, StorageType, IndexType> Matrix<DataType, IntType, StorageType, IndexType>
-{
+    impl <DataType, IntType, StorageType> Matrix<DataType, IntType, StorageType>{
         pub fn width(&self)->usize{
             self.width
         }
         pub fn height(&self)->usize{
             self.height
         }
-
+        pub fn topology(&self)->Topology{
+            self.topology
+        }
+        pub fn with_topology(mut self, topology: Topology)->Self{
+            self.topology = topology;
+            self
+        }
     }
-    impl <DataType, IntType, StorageType, IndexType> Matrix<DataType, IntType, StorageType, IndexType>
+    impl <DataType, IntType, StorageType> Matrix<DataType, IntType, StorageType>
         where StorageType: Clone{
         pub fn clone_data(&self)->StorageType{
             self.data.clone()
         }
     }
-
-    // Second General Impl
-    impl <DataType, IntType, StorageType, IndexType> Matrix<DataType, IntType, StorageType, IndexType>
-    where   Self: TypeToIndex<IndexType>,
-            DataType: Copy + std::cmp::PartialEq,
-            IndexType: Copy + std::hash::Hash + TryFrom<(IntType,IntType)> + std::cmp::Eq,
-            IntType: Copy + 
-                std::cmp::PartialOrd<IntType> + 
-                std::ops::Add<IntType, Output = IntType> + 
-                std::ops::Mul<IntType, Output = IntType> +
-                std::fmt::Debug +
-                TryFrom<usize>,
-
-            usize: TryFrom<IntType>,
-
-            <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-
-            <usize as TryFrom<IntType>>::Error: std::fmt::Debug,
-            StorageType: Clone
-    {
-        pub fn is_pos_valid(&self, pos: IndexType)->bool{
-                self.to_idx(pos).is_some()
+    impl <DataType, IntType: Coord, StorageType: GridStorage<DataType>> Matrix<DataType, IntType, StorageType>{
+        pub fn new_from_flat(width: usize, height: usize, data: StorageType)->Self{
+            Self{int_type: PhantomData, width, height, topology: Topology::Bounded, data}
+        }
+        /// The position `pos` stands for: itself if it is inside the matrix, where it wraps to in a toroidal one,
+        /// and `None` if it is off the edge of a bounded one.
+        pub fn wrap(&self, pos: Position<IntType>)->Option<Position<IntType>>{
+            match self.topology{
+                Topology::Bounded => self.is_pos_valid(pos).then_some(pos),
+                Topology::Toroidal => {
+                    if self.width == 0 || self.height == 0{
+                        return None;
+                    }
+                    let x = pos.x().to_i128()?.rem_euclid(self.width as i128);
+                    let y = pos.y().to_i128()?.rem_euclid(self.height as i128);
+                    Some(Position::new(IntType::from_i128(x)?, IntType::from_i128(y)?))
+                }
+            }
         }
-
-
-    }
-    // Implementing "TypeToindex" to allow for conversions between our "position" type and indices (when needed)
-    impl <DataType, IntType, StorageType, IndexType> TypeToIndex<Position<IntType>> for Matrix<DataType, IntType, StorageType, IndexType>
-    where   IntType: Copy + 
-                    std::hash::Hash +
-                    std::cmp::Eq +
-                    std::cmp::PartialOrd<IntType> + 
-                    std::ops::Add<IntType, Output = IntType> + 
-                    std::ops::Mul<IntType, Output = IntType> +
-                    std::fmt::Debug +
-                    TryFrom<usize>,
-
-            usize: TryFrom<IntType>,
-
-            <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-
-            <usize as TryFrom<IntType>>::Error: std::fmt::Debug,
-    {
-        fn to_idx(&self, input: Position<IntType>)->Option<usize> {
-            let width = self.width().try_into().ok()?;
-            let height = self.height().try_into().ok()?;
-            if input.x() >= width || input.y() >= height{
+        /// Where something at `pos` ends up after moving by `velocity` `times` times, i.e. `pos + velocity * times`.
+        /// In a toroidal matrix this wraps around as often as needed.
+        pub fn advance(&self, pos: Position<IntType>, velocity: Position<IntType>, times: IntType)->Option<Position<IntType>>{
+            let times = times.to_i128()?;
+            let moved = |start: IntType, speed: IntType, len: usize|->Option<i128>{
+                let (start, speed) = (start.to_i128()?, speed.to_i128()?);
+                match self.topology{
+                    Topology::Bounded => start.checked_add(speed.checked_mul(times)?),
+                    // Reducing first keeps large `times` from overflowing.
+                    Topology::Toroidal if len > 0 => {
+                        let len = len as i128;
+                        Some(start + (speed.rem_euclid(len) * times.rem_euclid(len)).rem_euclid(len))
+                    },
+                    Topology::Toroidal => None
+                }
+            };
+            let x = IntType::from_i128(moved(pos.x(), velocity.x(), self.width)?)?;
+            let y = IntType::from_i128(moved(pos.y(), velocity.y(), self.height)?)?;
+            self.wrap(Position::new(x, y))
+        }
+        pub fn to_idx(&self, input: Position<IntType>)->Option<usize>{
+            let input = match self.topology{
+                Topology::Bounded => input,
+                Topology::Toroidal => self.wrap(input)?
+            };
+            // Negative coordinates fail the conversion, so they are out of bounds too.
+            let x = input.x().to_usize()?;
+            let y = input.y().to_usize()?;
+            if x >= self.width || y >= self.height{
                 return None;
             }
-            let width_as_new_type: IntType = self.width.try_into().unwrap();
-            let idx = (input.y() * width_as_new_type) + input.x();
-            let idx_as_usize: usize = idx.try_into().unwrap();
-            Some( idx_as_usize )
+            Some(y*self.width + x)
         }
-
-        fn from_idx(&self, idx: usize)->Option<Position<IntType>> {
-            if idx < self.width()*self.height(){
-                let y = idx / self.width;
-                let x = idx % self.width;
-                Some(Position::<IntType>::try_from((x.try_into().unwrap(), y.try_into().unwrap())).ok().unwrap())
+        pub fn from_idx(&self, idx: usize)->Option<Position<IntType>>{
+            if idx < self.width*self.height{
+                Some(Position::new(IntType::from_usize(idx % self.width)?, IntType::from_usize(idx / self.width)?))
             }else{
                 None
             }
         }
+        pub fn is_pos_valid(&self, pos: Position<IntType>)->bool{
+            self.to_idx(pos).is_some()
+        }
+        /// The tile at `pos` by reference, for tiles that are not `Copy`.
+        pub fn tile(&self, pos: Position<IntType>)->Option<&DataType>{
+            self.data.tile(self.to_idx(pos)?)
+        }
+        /// The position one step in `direction` from `pos`, if that is still inside the matrix.
+        /// A toroidal matrix wraps around instead.
+        pub fn step(&self, pos: Position<IntType>, direction: Direction)->Option<Position<IntType>>{
+            let idx = self.to_idx(pos)?;
+            let vector = direction.as_vector();
+            let (width, height) = (self.width as isize, self.height as isize);
+            let x = (idx % self.width) as isize + vector.x() as isize;
+            let y = (idx / self.width) as isize + vector.y() as isize;
+            let (x, y) = match self.topology{
+                Topology::Bounded => (x, y),
+                Topology::Toroidal => (x.rem_euclid(width), y.rem_euclid(height))
+            };
+            if x < 0 || y < 0 || x >= width || y >= height{
+                return None;
+            }
+            self.from_idx(y as usize*self.width + x as usize)
+        }
+        /// The positions above, below, left and right of `pos`, in that order. `None` where that would leave a bounded matrix.
+        pub fn neighbours(&self, pos: Position<IntType>)->[Option<Position<IntType>>;4]{
+            [Direction::Up, Direction::Down, Direction::Left, Direction::Right].map(|direction| self.step(pos, direction))
+        }
+        /// Every stored tile together with its position.
+        pub fn enumerate(&self)->impl Iterator<Item = (Position<IntType>, &DataType)>{
+            self.data.stored().filter_map(|(idx,val)| Some((self.from_idx(idx)?, val)))
+        }
+        pub fn find_all(&self, target: &DataType)->impl Iterator<Item = Position<IntType>>
+        where DataType: PartialEq{
+            self.enumerate().filter(move |(_,val)| *val == target).map(|(pos,_)| pos)
+        }
+        /// The first position holding `target`, like the start or goal of a maze.
+        pub fn find(&self, target: &DataType)->Option<Position<IntType>>
+        where DataType: PartialEq{
+            self.find_all(target).next()
+        }
     }
     // Matrix with underlying HashMap as storage device.
-    impl <DataType, IntType, IndexType> Matrix<DataType,IntType, HashMap<IndexType,DataType>,IndexType>
-        where   DataType: Copy + std::cmp::PartialEq,
-                IndexType: Copy + std::hash::Hash + TryFrom<(IntType,IntType)> + std::cmp::Eq,
-                IntType: Copy + 
-                    std::hash::Hash +
-                    std::cmp::Eq +
-                    std::cmp::PartialOrd<IntType> + 
-                    std::ops::Add<IntType, Output = IntType> + 
-                    std::ops::Mul<IntType, Output = IntType> +
-                    std::fmt::Debug +
-                    TryFrom<usize>,
-
-                usize: TryFrom<IntType>,
-
-                <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-
-                <usize as TryFrom<IntType>>::Error: std::fmt::Debug,
-        {
-        pub fn new_from_square(data:Vec<Vec<DataType>>, filter_function: fn(&DataType)->bool)->Self{
+    impl <DataType, IntType: Coord> Matrix<DataType, IntType, HashMap<usize,DataType>>{
+        /// Only keeps the tiles `filter_function` is interested in.
+        pub fn new_sparse(data:Vec<Vec<DataType>>, filter_function: fn(&DataType)->bool)->Self{
             let height = data.len();
-            let width = data[0].len();
+            let width = data.first().map_or(0, Vec::len);
             let data = data.into_iter()
+                .flatten()
                 .enumerate()
-                .flat_map(|(y, row)| {
-                    row.into_iter()
-                        .enumerate()
-                        .filter(|(_,d)| filter_function(&d))
-                        .filter_map(move |(x,d)| {
-                            let x1: IntType = x.try_into().unwrap();
-                            let y1: IntType = y.try_into().unwrap();
-                            let idx_type = IndexType::try_from((x1,y1)).ok();
-                            if let Some(idx) = idx_type{
-                                Some((idx,d))
-                            }else{
-                                None
-                            }
-                        })
-                }).collect();
+                .filter(|(_,d)| filter_function(d))
+                .collect();
             Self::new_from_flat(width, height, data)
         }
-
-        pub fn new_from_flat(width: usize, height: usize, data:HashMap<IndexType,DataType>)->Self{
-            Self{index_type: PhantomData::<IndexType>, data_type: PhantomData::<DataType>, int_type: PhantomData::<IntType>, width, height, data}
-        }
-
-        pub fn find(&self, target: &DataType)->Option<IndexType>{
-            for (key,val) in self.data.iter(){
-                if val == target{
-                    return Some(*key);
-                }
-            }
-            None
-        }
-
     }
-    // Implementing a generic Get/Set for the underlying data structure for the HashMap based matrix. (Needed for Display)
-    impl <DataType, IntType, IndexType> GetSet< IndexType, DataType> for Matrix<DataType,IntType, HashMap<IndexType,DataType>, IndexType>
-    where   Self: TypeToIndex<IndexType>,
-            DataType: Copy + std::cmp::PartialEq,
-            IndexType: Copy + std::hash::Hash + TryFrom<(IntType,IntType)> + std::cmp::Eq,
-            IntType: Copy + 
-                std::hash::Hash +
-                std::cmp::Eq +
-                std::cmp::PartialOrd<IntType> + 
-                std::ops::Add<IntType, Output = IntType> + 
-                std::ops::Mul<IntType, Output = IntType> +
-                std::fmt::Debug +
-                TryFrom<usize>,
-
-            usize: TryFrom<IntType>,
-
-            <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-
-            <usize as TryFrom<IntType>>::Error: std::fmt::Debug,
-    {
-        fn get(&self,key: IndexType)->Option<DataType> {
-            if let Some(value) = self.data.get(&key){
-                Some(*value)
-            }else{
-                None
-            }
-        }
-    
-        fn set(&mut self,key: IndexType, value: DataType) {
-            if let Some(_) = self.to_idx(key){
-                self.data.insert(key, value);
-            }
-        }
-    }
-    
     // Matrix with underlying Vec as storage device.
-    impl <DataType, IntType, IndexType> Matrix<DataType,IntType, Vec<DataType>, IndexType>
-        where
-            Self: TypeToIndex<IndexType>,   
-            DataType: Copy + std::cmp::PartialEq,
-                IntType: Copy + 
-                    std::cmp::PartialOrd<IntType> + 
-                    std::ops::Add<IntType, Output = IntType> + 
-                    std::ops::Mul<IntType, Output = IntType> +
-                    std::fmt::Debug +
-                    TryFrom<usize>,
-
-                usize: TryFrom<IntType>,
-
-                <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-
-                <usize as TryFrom<IntType>>::Error: std::fmt::Debug,
-        {
-        pub fn new_from_flat(width: usize, height: usize, data:Vec<DataType>)->Self{
-            Self{index_type: PhantomData::<IndexType>, data_type: PhantomData::<DataType>, int_type: PhantomData::<IntType>, width, height, data }
-        }
+    impl <DataType, IntType: Coord> Matrix<DataType, IntType, Vec<DataType>>{
         pub fn new_from_square(data:Vec<Vec<DataType>>)->Self{
             let height = data.len();
-            let width = data[0].len();
-            let data = data.into_iter().flat_map(|row| row).collect();
+            let width = data.first().map_or(0, Vec::len);
+            let data = data.into_iter().flatten().collect();
             Self::new_from_flat(width, height, data)
         }
-
-        pub fn find(&self, target: &DataType)->Option<IndexType>{
-            for (idx, val) in self.data.iter().enumerate(){
-                if val == target{
-                    return self.from_idx(idx);
-                }
-            }
-            None
+        /// Every tile, row by row.
+        pub fn iter(&self)->std::slice::Iter<'_, DataType>{
+            self.data.iter()
+        }
+        pub fn iter_mut(&mut self)->std::slice::IterMut<'_, DataType>{
+            self.data.iter_mut()
+        }
+        pub fn rows(&self)->std::slice::Chunks<'_, DataType>{
+            // A grid without columns has no tiles either, so any chunk size gives no rows.
+            self.data.chunks(self.width.max(1))
+        }
+        pub fn columns(&self)->impl Iterator<Item = impl Iterator<Item = &DataType>>{
+            (0..self.width).map(move |x| self.data.iter().skip(x).step_by(self.width))
+        }
+        /// A matrix of the same shape with `f` applied to every tile.
+        pub fn map<NewType>(&self, f: impl Fn(&DataType)->NewType)->Matrix<NewType, IntType>{
+            Matrix::new_from_flat(self.width, self.height, self.data.iter().map(f).collect())
         }
     }
-    // Implementing a generic Get/Set for the underlying data structure for the HashMap based matrix. (Needed for Display)
-    impl <DataType, IntType,IndexType> GetSet<IndexType,DataType> for Matrix<DataType,IntType, Vec<DataType>, IndexType>
-    where   Self: TypeToIndex<IndexType>,
-            IndexType: Copy + std::hash::Hash + TryFrom<(IntType,IntType)> + std::cmp::Eq,
-            DataType: Copy + std::cmp::PartialEq,
-            IntType: Copy + 
-            std::cmp::PartialOrd<IntType> + 
-            std::ops::Add<IntType, Output = IntType> + 
-            std::ops::Mul<IntType, Output = IntType> +
-            std::fmt::Debug +
-            TryFrom<usize>,
-
-        usize: TryFrom<IntType>,
-
-        <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-
-        <usize as TryFrom<IntType>>::Error: std::fmt::Debug
+    impl <DataType, IntType, StorageType> GetSet<Position<IntType>, DataType> for Matrix<DataType, IntType, StorageType>
+    where   DataType: Copy,
+            IntType: Coord,
+            StorageType: GridStorage<DataType>
     {
-        fn get(&self,key: IndexType)->Option<DataType> {
+        fn get(&self,key: Position<IntType>)->Option<DataType> {
             let idx = self.to_idx(key)?;
-            if let Some(value) = self.data.get(idx){
-                Some(*value)
-            }else{
-                None
-            }
+            self.data.tile(idx).copied()
         }
-    
-        fn set(&mut self,key: IndexType, value: DataType) {
+
+        fn set(&mut self,key: Position<IntType>, value: DataType) {
             if let Some(idx) = self.to_idx(key){
-                self.data[idx]= value;
+                self.data.put(idx, value);
             }
         }
     }
-    // Semi-generic display implementation.
-    impl <DataType, IntType, StorageType, IndexType>Display for Matrix<DataType,IntType, StorageType, IndexType> 
-    where 
-        IndexType: Copy + std::hash::Hash + TryFrom<(IntType,IntType)> + std::cmp::Eq,
-        Self: GetSet<Position<IntType>,DataType>,
+    // Tiles that are not stored are shown as spaces.
+    impl <DataType, IntType, StorageType>Display for Matrix<DataType, IntType, StorageType>
+    where
         DataType: Display,
-        IntType: Display + TryFrom<usize>,
-        usize: TryFrom<IntType>,
-        <IntType as TryFrom<usize>>::Error : std::fmt::Debug,
-        <usize as TryFrom<IntType>>::Error: std::fmt::Debug,
+        StorageType: GridStorage<DataType>,
      {
         fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            let mut v = vec![];
             for y in 0..self.height {
                 for x in 0..self.width {
-                    let p = Position::new(x.try_into().unwrap(), y.try_into().unwrap());
-                    let s = match self.get(p){
-                        Some(data) => format!("{data}"),
-                        None => format!(" ")
-                    };
-                    v.push(s);
+                    match self.data.tile(y*self.width + x){
+                        Some(data) => write!(f,"{data}")?,
+                        None => write!(f," ")?
+                    }
                 }
-                v.push("\n".to_string());
+                writeln!(f)?;
             }
-            let output = v.join("");
-            write!(f,"{output}")
+            Ok(())
         }
-         }
+    }
 
+    #[cfg(test)]
+    mod tests{
+        use super::*;
+
+        fn digits()->Matrix<u8, usize>{
+            Matrix::new_from_square(vec![vec![1,2,3],vec![4,5,6]])
+        }
+        #[test]
+        fn positions_round_trip(){
+            let matrix = digits();
+            for idx in 0..6{
+                assert_eq!(matrix.to_idx(matrix.from_idx(idx).unwrap()), Some(idx));
+            }
+            assert_eq!(matrix.from_idx(6), None);
+            assert_eq!(matrix.to_idx(Position::new(3,0)), None);
+            let signed: Matrix<u8> = Matrix::new_from_square(vec![vec![1,2,3],vec![4,5,6]]);
+            assert_eq!(signed.get(Position::new(-1,1)), None);
+        }
+        #[test]
+        fn neighbours_stay_inside(){
+            let matrix = digits();
+            assert_eq!(matrix.neighbours(Position::new(0,0)), [None, Some(Position::new(0,1)), None, Some(Position::new(1,0))]);
+            assert_eq!(matrix.step(Position::new(2,1), Direction::Right), None);
+            assert_eq!(matrix.step(Position::new(2,1), Direction::Up), Some(Position::new(2,0)));
+        }
+        #[test]
+        fn empty_matrices_have_no_rows(){
+            let empty: Matrix<u8, usize> = Matrix::new_from_square(vec![]);
+            assert_eq!((empty.rows().count(), empty.columns().count()), (0, 0));
+            let no_columns: Matrix<u8, usize> = Matrix::new_from_square(vec![vec![], vec![]]);
+            assert_eq!(no_columns.rows().count(), 0);
+        }
+        #[test]
+        fn views(){
+            let mut matrix = digits();
+            assert_eq!(matrix.rows().collect::<Vec<_>>(), [[1,2,3],[4,5,6]]);
+            assert_eq!(matrix.columns().map(|column| column.copied().collect::<Vec<_>>()).collect::<Vec<_>>(), [[1,4],[2,5],[3,6]]);
+            assert_eq!(matrix.find_all(&5).collect::<Vec<_>>(), [Position::new(1,1)]);
+            matrix.iter_mut().for_each(|v| *v *= 2);
+            assert_eq!(matrix.enumerate().last(), Some((Position::new(2,1), &12)));
+            assert_eq!(matrix.map(|v| v+1).iter().sum::<u8>(), 48);
+            assert_eq!(matrix.to_string(), "246\n81012\n");
+        }
+        #[test]
+        fn toroidal_edges_wrap(){
+            let bounded: Matrix<u8> = Matrix::new_from_square(vec![vec![1,2,3],vec![4,5,6]]);
+            let matrix = bounded.clone().with_topology(Topology::Toroidal);
+            assert_eq!(matrix.neighbours(Position::new(0,0)), [Some(Position::new(0,1)), Some(Position::new(0,1)), Some(Position::new(2,0)), Some(Position::new(1,0))]);
+            assert_eq!(matrix.get(Position::new(3,-1)), Some(4));
+            assert_eq!(matrix.wrap(Position::new(3,-1)), Some(Position::new(0,1)));
+            assert_eq!(bounded.wrap(Position::new(3,-1)), None);
+            assert_eq!(digits().with_topology(Topology::Toroidal).step(Position::new(0,0), Direction::Left), Some(Position::new(2,0)));
+        }
+        #[test]
+        fn robots_teleport_across_the_room(){
+            // The robot from the day 14 example: p=2,4 v=2,-3 in an 11 x 7 room.
+            let room: Matrix<u8> = Matrix::new_from_flat(11, 7, vec![0; 77]).with_topology(Topology::Toroidal);
+            let (start, velocity) = (Position::new(2,4), Position::new(2,-3));
+            assert_eq!(room.advance(start, velocity, 1), Some(Position::new(4,1)));
+            assert_eq!(room.advance(start, velocity, 5), Some(Position::new(1,3)));
+            assert_eq!(room.advance(start, velocity, 5 + 77*1000), Some(Position::new(1,3)));
+            assert_eq!(room.wrap(start + velocity*5), Some(Position::new(1,3)));
+            let bounded: Matrix<u8> = Matrix::new_from_flat(11, 7, vec![0; 77]);
+            assert_eq!(bounded.advance(start, velocity, 1), Some(Position::new(4,1)));
+            assert_eq!(bounded.advance(start, velocity, 2), None);
+        }
+        #[test]
+        fn sparse_storage(){
+            let mut walls: Matrix<char, i64, HashMap<usize,char>> = Matrix::new_sparse(vec![vec!['#','.'],vec!['.','#']], |c| *c == '#');
+            assert_eq!(walls.get(Position::new(1,1)), Some('#'));
+            assert_eq!(walls.get(Position::new(1,0)), None);
+            walls.set(Position::new(1,0), '#');
+            walls.set(Position::new(5,0), '#');
+            assert_eq!(walls.to_string(), "##\n #\n");
+            assert!(walls.find(&'#').is_some());
+        }
+    }
 }
 
 
 pub mod astar{
-    use std::collections::HashMap;
+    use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, HashSet}, hash::Hash, ops::Add};
+
+    /// Something A* can find its way through: how states connect, what each step costs and a guess for what is left.
+    /// The guess must never be more than the real remaining cost, or the path found may not be the cheapest.
+    pub trait AStarTraversible{
+        type State: Clone + Eq + Hash;
+        type Cost: Copy + Ord + Add<Output = Self::Cost> + Default;
+        fn neighbours(&self, state: &Self::State)->impl IntoIterator<Item = Self::State>;
+        fn cost(&self, from: &Self::State, to: &Self::State)->Self::Cost;
+        fn heuristic(&self, state: &Self::State)->Self::Cost;
+        fn is_goal(&self, state: &Self::State)->bool;
+        /// The cheapest path from `start` to a goal, both included, and what it costs.
+        fn a_star(&self, start: Self::State)->Option<(Vec<Self::State>, Self::Cost)>{
+            a_star(start, |state| self.is_goal(state), |state| self.neighbours(state).into_iter().collect::<Vec<_>>(), |from, to| self.cost(from, to), |state| self.heuristic(state))
+        }
+    }
+
+    /// The cheapest path from `start` to the first state `is_goal` accepts, both included, and what it costs.
+    ///
+    /// `Cost::default()` is taken as zero. `heuristic` must never overestimate the remaining cost;
+    /// a heuristic of zero turns this into Dijkstra.
+    pub fn a_star<State, Cost, Neighbours>(
+        start: State,
+        is_goal: impl Fn(&State)->bool,
+        neighbours: impl Fn(&State)->Neighbours,
+        cost: impl Fn(&State, &State)->Cost,
+        heuristic: impl Fn(&State)->Cost,
+    )->Option<(Vec<State>, Cost)>
+    where   State: Clone + Eq + Hash,
+            Cost: Copy + Ord + Add<Output = Cost> + Default,
+            Neighbours: IntoIterator<Item = State>
+    {
+        // States are numbered as they are found, so the heap only has to order numbers.
+        let mut states = vec![start.clone()];
+        let mut ids = HashMap::from([(start.clone(), 0)]);
+        let mut best = vec![Cost::default()];
+        let mut came_from: Vec<Option<usize>> = vec![None];
+        let mut open_set = BinaryHeap::from([Reverse((heuristic(&start), Cost::default(), 0))]);
+        while let Some(Reverse((_, score, id))) = open_set.pop(){
+            if score > best[id]{
+                continue; // A cheaper way here was found after this one was queued.
+            }
+            if is_goal(&states[id]){
+                let mut path = vec![states[id].clone()];
+                let mut current = id;
+                while let Some(previous) = came_from[current]{
+                    path.push(states[previous].clone());
+                    current = previous;
+                }
+                path.reverse();
+                return Some((path, score));
+            }
+            let current = states[id].clone();
+            for neighbour in neighbours(&current){
+                let tentative = score + cost(&current, &neighbour);
+                let neighbour_id = match ids.get(&neighbour){
+                    Some(&known) if tentative >= best[known] => continue,
+                    Some(&known) => known,
+                    None => {
+                        states.push(neighbour.clone());
+                        best.push(tentative);
+                        came_from.push(None);
+                        ids.insert(neighbour.clone(), states.len()-1);
+                        states.len()-1
+                    }
+                };
+                best[neighbour_id] = tentative;
+                came_from[neighbour_id] = Some(id);
+                open_set.push(Reverse((tentative + heuristic(&neighbour), tentative, neighbour_id)));
+            }
+        }
+        None
+    }
+
+    /// Every cheapest way to a goal, as found by [`dijkstra_all`].
+    #[derive(Debug, Clone)]
+    pub struct BestPaths<State, Cost>{
+        cost: Cost,
+        goals: Vec<State>,
+        predecessors: HashMap<State, Vec<State>>
+    }
+    impl <State: Clone + Eq + Hash, Cost: Copy> BestPaths<State, Cost>{
+        pub fn cost(&self)->Cost{
+            self.cost
+        }
+        /// The goal states that were reached at the best cost.
+        pub fn goals(&self)->&[State]{
+            &self.goals
+        }
+        /// Every state on at least one of the cheapest paths.
+        pub fn states(&self)->HashSet<State>{
+            let mut seen: HashSet<State> = self.goals.iter().cloned().collect();
+            let mut queue = self.goals.clone();
+            while let Some(state) = queue.pop(){
+                for previous in self.predecessors.get(&state).into_iter().flatten(){
+                    if seen.insert(previous.clone()){
+                        queue.push(previous.clone());
+                    }
+                }
+            }
+            seen
+        }
+        /// Every cheapest path, start and goal included. There can be a lot of them; [`BestPaths::states`] is cheaper
+        /// when only the states matter.
+        pub fn paths(&self)->Vec<Vec<State>>{
+            let mut finished = Vec::new();
+            let mut partial: Vec<Vec<State>> = self.goals.iter().map(|goal| vec![goal.clone()]).collect();
+            while let Some(path) = partial.pop(){
+                let last = path.last().expect("Paths are never empty.");
+                match self.predecessors.get(last){
+                    Some(previous) if !previous.is_empty() => {
+                        for state in previous{
+                            let mut longer = path.clone();
+                            longer.push(state.clone());
+                            partial.push(longer);
+                        }
+                    },
+                    _ => {
+                        let mut path = path;
+                        path.reverse();
+                        finished.push(path);
+                    }
+                }
+            }
+            finished
+        }
+    }
 
-    use crate::matrix::GetSet;
-    pub trait AStartTraversible<PositionType, GoalType, ScoreType, StoredDataType, ValueType>
-    where PositionType: Copy + std::hash::Hash + PartialEq<GoalType>,
-            ScoreType: Copy,
-            ValueType: Copy,
-            StoredDataType: GetSet<PositionType,ValueType>,
-        {
-        fn a_start(&self, start: PositionType, goal: GoalType)->Option<Vec<GoalType>>;
-        fn heuristic(&self, pos: PositionType, goal: GoalType)->ScoreType;
-        fn reconstruct_path(&self, came_from: HashMap<PositionType,PositionType>, current: PositionType)->Vec<PositionType>;
+    /// Dijkstra from `start` that keeps every predecessor giving the best cost, instead of only the first one found,
+    /// so all the cheapest paths to the goal can be recovered. `None` if no goal can be reached.
+    pub fn dijkstra_all<State, Cost, Neighbours>(
+        start: State,
+        is_goal: impl Fn(&State)->bool,
+        neighbours: impl Fn(&State)->Neighbours,
+        cost: impl Fn(&State, &State)->Cost,
+    )->Option<BestPaths<State, Cost>>
+    where   State: Clone + Eq + Hash,
+            Cost: Copy + Ord + Add<Output = Cost> + Default,
+            Neighbours: IntoIterator<Item = State>
+    {
+        let mut states = vec![start.clone()];
+        let mut ids = HashMap::from([(start, 0)]);
+        let mut best = vec![Cost::default()];
+        let mut came_from: Vec<Vec<usize>> = vec![vec![]];
+        let mut open_set = BinaryHeap::from([Reverse((Cost::default(), 0))]);
+        let mut goal: Option<(Cost, Vec<usize>)> = None;
+        while let Some(Reverse((score, id))) = open_set.pop(){
+            if score > best[id]{
+                continue;
+            }
+            if let Some((goal_cost, _)) = &goal && score > *goal_cost{
+                break; // Everything left is more expensive than the goal.
+            }
+            if is_goal(&states[id]){
+                goal.get_or_insert_with(|| (score, vec![])).1.push(id);
+                continue;
+            }
+            let current = states[id].clone();
+            for neighbour in neighbours(&current){
+                let tentative = score + cost(&current, &neighbour);
+                match ids.get(&neighbour){
+                    Some(&known) if tentative > best[known] => (),
+                    Some(&known) if tentative == best[known] => came_from[known].push(id),
+                    Some(&known) => {
+                        best[known] = tentative;
+                        came_from[known] = vec![id];
+                        open_set.push(Reverse((tentative, known)));
+                    },
+                    None => {
+                        states.push(neighbour.clone());
+                        best.push(tentative);
+                        came_from.push(vec![id]);
+                        ids.insert(neighbour, states.len()-1);
+                        open_set.push(Reverse((tentative, states.len()-1)));
+                    }
+                }
+            }
+        }
+        let (cost, goals) = goal?;
+        let predecessors = came_from.into_iter().enumerate()
+            .filter(|(_, previous)| !previous.is_empty())
+            .map(|(id, previous)| (states[id].clone(), previous.into_iter().map(|p| states[p].clone()).collect()))
+            .collect();
+        Some(BestPaths{cost, goals: goals.into_iter().map(|id| states[id].clone()).collect(), predecessors})
+    }
+
+    #[cfg(test)]
+    mod tests{
+        use super::*;
+        use crate::position::Position;
+
+        struct Maze{
+            walls: Vec<&'static str>,
+            goal: Position<i32>
+        }
+        impl AStarTraversible for Maze{
+            type State = Position<i32>;
+            type Cost = i32;
+            fn neighbours(&self, state: &Position<i32>)->impl IntoIterator<Item = Position<i32>>{
+                state.neighbours().into_iter().flatten().filter(|p| self.walls.get(p.y() as usize).and_then(|row| row.as_bytes().get(p.x() as usize)) == Some(&b'.'))
+            }
+            fn cost(&self, _from: &Position<i32>, _to: &Position<i32>)->i32{
+                1
+            }
+            fn heuristic(&self, state: &Position<i32>)->i32{
+                state.abs_diff(&self.goal)
+            }
+            fn is_goal(&self, state: &Position<i32>)->bool{
+                *state == self.goal
+            }
+        }
+        #[test]
+        fn finds_the_cheapest_path(){
+            let maze = Maze{walls: vec![
+                "....#",
+                ".##.#",
+                "..#..",
+                "#...#"], goal: Position::new(4,2)};
+            let (path, cost) = maze.a_star(Position::new(0,0)).unwrap();
+            assert_eq!(cost, 6);
+            assert_eq!(path.len(), 7);
+            assert_eq!((path[0], path[6]), (Position::new(0,0), Position::new(4,2)));
+            assert!(path.windows(2).all(|step| step[0].abs_diff(&step[1]) == 1));
+            let walled_in = Maze{walls: vec![".#.", "##."], goal: Position::new(2,1)};
+            assert_eq!(walled_in.a_star(Position::new(0,0)), None);
+        }
+        #[test]
+        fn costs_decide_over_steps(){
+            // Going through 'b' is one step shorter but far more expensive.
+            let graph = HashMap::from([('a', vec![('b', 10), ('c', 1)]), ('b', vec![('z', 1)]), ('c', vec![('d', 1)]), ('d', vec![('z', 1)])]);
+            let cost_of = |from: &char, to: &char| graph[from].iter().find(|(n,_)| n == to).unwrap().1;
+            let neighbours = |state: &char| graph.get(state).into_iter().flatten().map(|(n,_)| *n).collect::<Vec<_>>();
+            assert_eq!(a_star('a', |s| *s == 'z', neighbours, cost_of, |_| 0), Some((vec!['a','c','d','z'], 3)));
+        }
+        #[test]
+        fn keeps_every_cheapest_path(){
+            // Two ways of cost 3 from 'a' to 'z', one of cost 4 and a dead end.
+            let graph = HashMap::from([('a', vec![('b', 1), ('c', 2), ('d', 1), ('x', 1)]), ('b', vec![('z', 2)]), ('c', vec![('z', 1)]), ('d', vec![('z', 3)])]);
+            let cost_of = |from: &char, to: &char| graph[from].iter().find(|(n,_)| n == to).unwrap().1;
+            let neighbours = |state: &char| graph.get(state).into_iter().flatten().map(|(n,_)| *n).collect::<Vec<_>>();
+            let best = dijkstra_all('a', |s| *s == 'z', neighbours, cost_of).unwrap();
+            assert_eq!((best.cost(), best.goals()), (3, &['z'][..]));
+            assert_eq!(best.states(), HashSet::from(['a','b','c','z']));
+            let mut paths = best.paths();
+            paths.sort();
+            assert_eq!(paths, [vec!['a','b','z'], vec!['a','c','z']]);
+            assert!(dijkstra_all('a', |s| *s == 'q', neighbours, cost_of).is_none());
+        }
     }
 }
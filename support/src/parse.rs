@@ -0,0 +1,207 @@
+use std::{fmt::Display, process::exit, str::FromStr};
+
+/// Error for puzzle input that does not look the way a day expects it to.
+///
+/// `line` and `column` are 1-based and point at the start of `text`, the offending part of the input.
+/// A `line` of 0 means the error is about the input as a whole (e.g. a missing start tile).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError{
+    line: usize,
+    column: usize,
+    text: String,
+    message: String
+}
+impl ParseError{
+    pub fn new(line: usize, column: usize, text: impl Into<String>, message: impl Into<String>)->Self{
+        Self{line, column, text: text.into(), message: message.into()}
+    }
+    /// An error that is not tied to any one place in the input.
+    pub fn whole_input(message: impl Into<String>)->Self{
+        Self::new(0, 0, "", message)
+    }
+    /// An error for `text`, which has to be a slice of `source`; the line and column are worked out from where it sits.
+    /// If `text` is not part of `source` the error is treated like [`ParseError::whole_input`], but keeps the text.
+    pub fn at(source: &str, text: &str, message: impl Into<String>)->Self{
+        let start = source.as_ptr() as usize;
+        let offset = (text.as_ptr() as usize).wrapping_sub(start);
+        if offset.checked_add(text.len()).is_none_or(|end| end > source.len()){
+            return Self::new(0, 0, text, message);
+        }
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|idx| idx+1).unwrap_or(0);
+        let column = source[line_start..offset].chars().count() + 1;
+        Self::new(line, column, text, message)
+    }
+    pub fn line(&self)->usize{
+        self.line
+    }
+    pub fn column(&self)->usize{
+        self.column
+    }
+    pub fn text(&self)->&str{
+        &self.text
+    }
+    pub fn message(&self)->&str{
+        &self.message
+    }
+}
+impl Display for ParseError{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line > 0{
+            write!(f,"{}:{}: ",self.line,self.column)?;
+        }
+        write!(f,"{}",self.message)?;
+        if !self.text.is_empty(){
+            write!(f,", found {:?}",self.text)?;
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for ParseError{}
+
+/// Parses `text` (a slice of `source`) as a number, pointing at it if that fails.
+pub fn number<T: FromStr>(source: &str, text: &str)->Result<T,ParseError>{
+    text.parse().map_err(|_| ParseError::at(source, text, "expected a number"))
+}
+
+/// Parses every `sep` separated item of `text` as a number. Spaces around an item are ignored,
+/// but an empty item is an error.
+pub fn separated<T: FromStr>(source: &str, text: &str, sep: char)->Result<Vec<T>,ParseError>{
+    text.split(sep).map(|item| number(source, item.trim())).collect()
+}
+/// Parses every whitespace separated item of `text` as a number.
+pub fn whitespace_separated<T: FromStr>(source: &str, text: &str)->Result<Vec<T>,ParseError>{
+    text.split_whitespace().map(|item| number(source, item)).collect()
+}
+/// The non-empty `sep` separated items of `text`, trimmed. For lists of words rather than numbers.
+pub fn fields(text: &str, sep: char)->impl Iterator<Item = &str>{
+    text.split(sep).map(str::trim).filter(|item| !item.is_empty())
+}
+/// Every integer in `text`, skipping whatever is between them. A `-` or `+` right before the digits is kept,
+/// so "x=-3..+5" gives `[-3, 5]`.
+pub fn integers<T: FromStr>(source: &str, text: &str)->Result<Vec<T>,ParseError>{
+    let mut found = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(|c: char| c.is_ascii_digit()){
+        let start = if rest[..start].ends_with(['-','+']) { start-1 } else { start };
+        let end = rest[start+1..].find(|c: char| !c.is_ascii_digit()).map_or(rest.len(), |len| start+1+len);
+        found.push(number(source, &rest[start..end])?);
+        rest = &rest[end..];
+    }
+    Ok(found)
+}
+/// Like [`integers`], but `text` has to hold exactly `N` of them.
+pub fn n_integers<T: FromStr, const N: usize>(source: &str, text: &str)->Result<[T;N],ParseError>{
+    let found = integers(source, text)?;
+    let count = found.len();
+    found.try_into().map_err(|_| ParseError::at(source, text, format!("expected {N} numbers, found {count}")))
+}
+/// Splits `text` at the first `sep` into a trimmed key and value, e.g. `"190: 10 19"` or `"47|53"`.
+pub fn key_value<'a>(source: &str, text: &'a str, sep: &str)->Result<(&'a str,&'a str),ParseError>{
+    match text.split_once(sep){
+        Some((key,value)) => Ok((key.trim(), value.trim())),
+        None => Err(ParseError::at(source, text, format!("expected \"<key>{sep}<value>\"")))
+    }
+}
+/// Parses the value right after `label` in `text`, up to the next comma, semicolon or whitespace.
+/// With a label of `"X+"` this reads `94` out of `"Button A: X+94, Y+34"`.
+pub fn labelled<T: FromStr>(source: &str, text: &str, label: &str)->Result<T,ParseError>{
+    let Some(idx) = text.find(label) else {
+        return Err(ParseError::at(source, text, format!("expected {label:?} followed by a number")));
+    };
+    let rest = &text[idx+label.len()..];
+    let end = rest.find(|c: char| c == ',' || c == ';' || c.is_whitespace()).unwrap_or(rest.len());
+    number(source, &rest[..end])
+}
+/// Two [`labelled`] values, like the `X=8400, Y=5400` of a coordinate.
+pub fn labelled_pair<T: FromStr>(source: &str, text: &str, (x_label, y_label): (&str,&str))->Result<(T,T),ParseError>{
+    Ok((labelled(source, text, x_label)?, labelled(source, text, y_label)?))
+}
+/// Reads `lines` as a rectangular grid, turning every character into a tile with `tile`.
+/// `expected` describes the valid characters for the error message when `tile` gives back `None`.
+/// Whole inputs are easier read with [`crate::input::Input::grid`]; this is for a single block of one.
+pub fn grid<T>(source: &str, lines: &[&str], tile: impl Fn(char)->Option<T>, expected: &str)->Result<Vec<Vec<T>>,ParseError>{
+    let width = match lines.first(){
+        Some(line) if !line.is_empty() => line.chars().count(),
+        _ => return Err(ParseError::whole_input("the grid is empty"))
+    };
+    lines.iter().map(|line|{
+        if line.chars().count() != width{
+            return Err(ParseError::at(source, line, format!("expected every row to be {width} wide")));
+        }
+        line.char_indices().map(|(col,c)|{
+            tile(c).ok_or_else(|| ParseError::at(source, &line[col..col+c.len_utf8()], format!("expected {expected}")))
+        }).collect()
+    }).collect()
+}
+
+/// Gives back the parsed value, or prints where `file_name` went wrong and exits.
+/// Meant for the `main()` of each day, where a backtrace is less useful than a location.
+pub fn or_exit<T>(file_name: &str, result: Result<T,ParseError>)->T{
+    match result{
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("{file_name}:{e}");
+            exit(1)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn locates_slices_of_the_input(){
+        let source = "12 34\n56 x7\n";
+        let text = &source[9..11];
+        let e = ParseError::at(source, text, "expected a number");
+        assert_eq!((e.line(), e.column(), e.text()), (2, 4, "x7"));
+        assert_eq!(e.to_string(), "2:4: expected a number, found \"x7\"");
+    }
+    #[test]
+    fn text_outside_the_input_has_no_location(){
+        let elsewhere = String::from("elsewhere");
+        let e = ParseError::at("12 34", &elsewhere, "unexpected");
+        assert_eq!((e.line(), e.column()), (0, 0));
+        assert_eq!(e.to_string(), "unexpected, found \"elsewhere\"");
+    }
+    #[test]
+    fn numbers_report_their_position(){
+        let source = "1,2,three";
+        assert_eq!(number::<u8>(source, &source[2..3]), Ok(2));
+        let e = number::<u8>(source, &source[4..]).unwrap_err();
+        assert_eq!((e.line(), e.column()), (1, 5));
+    }
+    #[test]
+    fn lists_and_pairs(){
+        let source = "47|53\n75, 47,61\n190: 10  19";
+        let lines: Vec<&str> = source.lines().collect();
+        assert_eq!(key_value(source, lines[0], "|"), Ok(("47","53")));
+        assert_eq!(separated::<u8>(source, lines[1], ','), Ok(vec![75,47,61]));
+        let (key, value) = key_value(source, lines[2], ":").unwrap();
+        assert_eq!((key, whitespace_separated::<u8>(source, value)), ("190", Ok(vec![10,19])));
+        assert_eq!(fields(" r, wr,,b ", ',').collect::<Vec<_>>(), ["r","wr","b"]);
+        assert_eq!(key_value(source, lines[1], "|").unwrap_err().line(), 2);
+    }
+    #[test]
+    fn integers_keep_their_sign(){
+        let source = "p=0,4 v=3,-3\nx=-3..+5, y=12";
+        let (first, second) = source.split_once('\n').unwrap();
+        assert_eq!(integers::<i32>(source, first), Ok(vec![0,4,3,-3]));
+        assert_eq!(n_integers::<i32,3>(source, second), Ok([-3,5,12]));
+        assert_eq!(n_integers::<i32,2>(source, second).unwrap_err().message(), "expected 2 numbers, found 3");
+        assert_eq!(integers::<u8>(source, "no numbers"), Ok(vec![]));
+    }
+    #[test]
+    fn labelled_coordinates(){
+        let source = "Button A: X+94, Y+34\nPrize: X=8400, Y=54o0";
+        let (button, prize) = source.split_once('\n').unwrap();
+        assert_eq!(labelled_pair::<i64>(source, button, ("X+","Y+")), Ok((94,34)));
+        let e = labelled_pair::<i64>(source, prize, ("X=","Y=")).unwrap_err();
+        assert_eq!((e.line(), e.column(), e.text()), (2, 18, "54o0"));
+        let e = labelled_pair::<i64>(source, prize, ("X+","Y+")).unwrap_err();
+        assert_eq!((e.line(), e.column(), e.message()), (2, 1, "expected \"X+\" followed by a number"));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{coord::Coord, direction::Direction, matrix::{GetSet, GridStorage, Matrix}, position::Position};
+
+/// One connected group of tiles, as found by [`regions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region<IntType>{
+    cells: Vec<Position<IntType>>,
+    perimeter: usize,
+    sides: usize,
+    bounds: (Position<IntType>, Position<IntType>)
+}
+impl <IntType: Coord> Region<IntType>{
+    fn new(labels: &Matrix<Option<usize>, IntType>, label: usize, cells: Vec<Position<IntType>>)->Self{
+        let inside = |pos: Option<Position<IntType>>| pos.and_then(|pos| labels.get(pos).flatten()) == Some(label);
+        let mut perimeter = 0;
+        let mut sides = 0;
+        for &pos in &cells{
+            perimeter += labels.neighbours(pos).into_iter().filter(|&next| !inside(next)).count();
+            // A region has as many straight sides as it has corners, and every corner belongs to exactly one cell.
+            for (a, b) in [(Direction::Up, Direction::Left), (Direction::Up, Direction::Right), (Direction::Down, Direction::Left), (Direction::Down, Direction::Right)]{
+                let (side_a, side_b) = (labels.step(pos, a), labels.step(pos, b));
+                let diagonal = side_a.and_then(|side| labels.step(side, b));
+                match (inside(side_a), inside(side_b)){
+                    (false, false) => sides += 1,
+                    (true, true) if !inside(diagonal) => sides += 1,
+                    _ => ()
+                }
+            }
+        }
+        let xs = cells.iter().map(|pos| pos.x());
+        let ys = cells.iter().map(|pos| pos.y());
+        let min = Position::new(xs.clone().min().unwrap_or_default(), ys.clone().min().unwrap_or_default());
+        let max = Position::new(xs.max().unwrap_or_default(), ys.max().unwrap_or_default());
+        Self{cells, perimeter, sides, bounds: (min, max)}
+    }
+    pub fn cells(&self)->&[Position<IntType>]{
+        &self.cells
+    }
+    pub fn area(&self)->usize{
+        self.cells.len()
+    }
+    /// The number of tile edges between the region and everything outside of it.
+    pub fn perimeter(&self)->usize{
+        self.perimeter
+    }
+    /// The number of straight sides of the fence around the region, holes included.
+    pub fn sides(&self)->usize{
+        self.sides
+    }
+    /// The top left and bottom right corners of the smallest box holding the region.
+    pub fn bounds(&self)->(Position<IntType>, Position<IntType>){
+        self.bounds
+    }
+}
+
+/// Every region of a grid, and which region each tile belongs to.
+#[derive(Debug, Clone)]
+pub struct Regions<IntType>{
+    labels: Matrix<Option<usize>, IntType>,
+    regions: Vec<Region<IntType>>
+}
+impl <IntType: Coord> Regions<IntType>{
+    /// The index into [`Regions::iter`] of the region every tile belongs to. `None` where the grid has no tile.
+    pub fn labels(&self)->&Matrix<Option<usize>, IntType>{
+        &self.labels
+    }
+    pub fn region_of(&self, pos: Position<IntType>)->Option<&Region<IntType>>{
+        self.regions.get(self.labels.get(pos).flatten()?)
+    }
+    pub fn iter(&self)->std::slice::Iter<'_, Region<IntType>>{
+        self.regions.iter()
+    }
+    pub fn len(&self)->usize{
+        self.regions.len()
+    }
+    pub fn is_empty(&self)->bool{
+        self.regions.is_empty()
+    }
+}
+
+/// Splits `grid` into connected regions, where neighbouring tiles belong together if `same` says so.
+/// Regions are numbered in the order their first tile shows up, row by row.
+pub fn regions<DataType, IntType: Coord, StorageType: GridStorage<DataType>>(
+    grid: &Matrix<DataType, IntType, StorageType>,
+    same: impl Fn(&DataType, &DataType)->bool,
+)->Regions<IntType>{
+    let mut labels = Matrix::new_from_flat(grid.width(), grid.height(), vec![None; grid.width()*grid.height()])
+        .with_topology(grid.topology());
+    let mut found = Vec::new();
+    for (start, _) in grid.enumerate(){
+        if labels.get(start).flatten().is_some(){
+            continue;
+        }
+        let label = found.len();
+        labels.set(start, Some(label));
+        let mut cells = vec![start];
+        let mut queue = vec![start];
+        while let Some(pos) = queue.pop(){
+            let Some(tile) = grid.tile(pos) else{
+                continue;
+            };
+            for next in grid.neighbours(pos).into_iter().flatten(){
+                if labels.get(next).flatten().is_none() && grid.tile(next).is_some_and(|other| same(tile, other)){
+                    labels.set(next, Some(label));
+                    cells.push(next);
+                    queue.push(next);
+                }
+            }
+        }
+        found.push(cells);
+    }
+    let regions = found.into_iter().enumerate().map(|(label, cells)| Region::new(&labels, label, cells)).collect();
+    Regions{labels, regions}
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    fn garden(rows: &[&str])->Matrix<char, usize>{
+        Matrix::new_from_square(rows.iter().map(|row| row.chars().collect()).collect())
+    }
+    #[test]
+    fn measures_regions(){
+        let garden = garden(&["AAAA", "BBCD", "BBCC", "EEEC"]);
+        let found = regions(&garden, |a, b| a == b);
+        let summary = found.iter().map(|region| (region.area(), region.perimeter(), region.sides())).collect::<Vec<_>>();
+        assert_eq!(summary, [(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]);
+        let c = found.region_of(Position::new(2, 1)).unwrap();
+        assert_eq!(c.bounds(), (Position::new(2, 1), Position::new(3, 3)));
+        assert_eq!(found.labels().get(Position::new(3, 3)), Some(Some(2)));
+    }
+    #[test]
+    fn counts_the_sides_of_holes(){
+        let garden = garden(&["EEEEE", "EXXXX", "EEEEE", "EXXXX", "EEEEE"]);
+        let found = regions(&garden, |a, b| a == b);
+        assert_eq!(found.len(), 3);
+        let e = found.region_of(Position::new(0, 0)).unwrap();
+        assert_eq!((e.area(), e.sides()), (17, 12));
+        // Inside another region the sides of the hole count too.
+        let garden = self::garden(&["AAAAAA", "AAABBA", "AAABBA", "ABBAAA", "ABBAAA", "AAAAAA"]);
+        let found = regions(&garden, |a, b| a == b);
+        assert_eq!(found.iter().map(|region| region.area()*region.sides()).sum::<usize>(), 368);
+    }
+}
@@ -0,0 +1,260 @@
+use std::{cmp::Reverse, collections::{BinaryHeap, HashMap, VecDeque}, hash::Hash, ops::Add};
+
+use crate::{coord::Coord, matrix::{GetSet, GridStorage, Matrix}, position::Position};
+
+/// How far every reachable state is from the closest source, and which state it was first reached from.
+#[derive(Debug, Clone)]
+pub struct Distances<State, Cost>{
+    distances: HashMap<State, Cost>,
+    predecessors: HashMap<State, State>
+}
+impl <State: Clone + Eq + Hash, Cost: Copy> Distances<State, Cost>{
+    pub fn distance(&self, state: &State)->Option<Cost>{
+        self.distances.get(state).copied()
+    }
+    /// The state `state` was reached from. `None` for the sources and for states that were never reached.
+    pub fn predecessor(&self, state: &State)->Option<&State>{
+        self.predecessors.get(state)
+    }
+    /// Every reached state with its distance, sources included.
+    pub fn reached(&self)->impl Iterator<Item = (&State, Cost)>{
+        self.distances.iter().map(|(state, cost)| (state, *cost))
+    }
+    pub fn len(&self)->usize{
+        self.distances.len()
+    }
+    pub fn is_empty(&self)->bool{
+        self.distances.is_empty()
+    }
+    /// A shortest path from the closest source to `state`, both ends included.
+    pub fn path_to(&self, state: &State)->Option<Vec<State>>{
+        self.distances.contains_key(state).then(|| walk_back(&self.predecessors, state.clone()))
+    }
+    pub fn into_parts(self)->(HashMap<State, Cost>, HashMap<State, State>){
+        (self.distances, self.predecessors)
+    }
+}
+
+fn walk_back<State: Clone + Eq + Hash>(predecessors: &HashMap<State, State>, target: State)->Vec<State>{
+    let mut path = vec![target];
+    while let Some(previous) = predecessors.get(path.last().expect("Paths are never empty.")){
+        path.push(previous.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth first search from all of `sources` at once, where every step costs 1.
+pub fn bfs<State, Neighbours>(
+    sources: impl IntoIterator<Item = State>,
+    neighbours: impl Fn(&State)->Neighbours,
+)->Distances<State, u32>
+where   State: Clone + Eq + Hash,
+        Neighbours: IntoIterator<Item = State>
+{
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    let mut queue = VecDeque::new();
+    for source in sources{
+        if !distances.contains_key(&source){
+            distances.insert(source.clone(), 0);
+            queue.push_back(source);
+        }
+    }
+    while let Some(current) = queue.pop_front(){
+        let distance = distances[&current] + 1;
+        for neighbour in neighbours(&current){
+            if distances.contains_key(&neighbour){
+                continue;
+            }
+            distances.insert(neighbour.clone(), distance);
+            predecessors.insert(neighbour.clone(), current.clone());
+            queue.push_back(neighbour);
+        }
+    }
+    Distances{distances, predecessors}
+}
+
+/// Dijkstra from all of `sources` at once, with `cost` giving the price of a single step.
+pub fn dijkstra<State, Cost, Neighbours>(
+    sources: impl IntoIterator<Item = State>,
+    neighbours: impl Fn(&State)->Neighbours,
+    cost: impl Fn(&State, &State)->Cost,
+)->Distances<State, Cost>
+where   State: Clone + Eq + Hash,
+        Cost: Copy + Ord + Add<Output = Cost> + Default,
+        Neighbours: IntoIterator<Item = State>
+{
+    // States get a number, so the heap does not need them to be `Ord`.
+    let mut states = Vec::new();
+    let mut ids = HashMap::new();
+    let mut best = Vec::new();
+    let mut came_from: Vec<Option<usize>> = Vec::new();
+    let mut open_set = BinaryHeap::new();
+    for source in sources{
+        if !ids.contains_key(&source){
+            ids.insert(source.clone(), states.len());
+            open_set.push(Reverse((Cost::default(), states.len())));
+            states.push(source);
+            best.push(Cost::default());
+            came_from.push(None);
+        }
+    }
+    while let Some(Reverse((score, id))) = open_set.pop(){
+        if score > best[id]{
+            continue;
+        }
+        let current = states[id].clone();
+        for neighbour in neighbours(&current){
+            let tentative = score + cost(&current, &neighbour);
+            match ids.get(&neighbour){
+                Some(&known) if tentative >= best[known] => (),
+                Some(&known) => {
+                    best[known] = tentative;
+                    came_from[known] = Some(id);
+                    open_set.push(Reverse((tentative, known)));
+                },
+                None => {
+                    ids.insert(neighbour.clone(), states.len());
+                    open_set.push(Reverse((tentative, states.len())));
+                    states.push(neighbour);
+                    best.push(tentative);
+                    came_from.push(Some(id));
+                }
+            }
+        }
+    }
+    let predecessors = came_from.iter().enumerate()
+        .filter_map(|(id, previous)| Some((states[id].clone(), states[(*previous)?].clone())))
+        .collect();
+    let distances = states.into_iter().zip(best).collect();
+    Distances{distances, predecessors}
+}
+
+/// [`Distances`] over the tiles of a [`Matrix`], as a matrix of the same shape.
+#[derive(Debug, Clone)]
+pub struct GridDistances<IntType>{
+    distances: Matrix<Option<u32>, IntType>,
+    predecessors: HashMap<Position<IntType>, Position<IntType>>
+}
+impl <IntType: Coord> GridDistances<IntType>{
+    fn new<DataType, StorageType: GridStorage<DataType>>(grid: &Matrix<DataType, IntType, StorageType>, found: Distances<Position<IntType>, u32>)->Self{
+        let (found, predecessors) = found.into_parts();
+        let mut distances = Matrix::new_from_flat(grid.width(), grid.height(), vec![None; grid.width()*grid.height()])
+            .with_topology(grid.topology());
+        for (pos, distance) in found{
+            distances.set(pos, Some(distance));
+        }
+        Self{distances, predecessors}
+    }
+    /// `None` for every tile that can not be reached.
+    pub fn distances(&self)->&Matrix<Option<u32>, IntType>{
+        &self.distances
+    }
+    pub fn into_matrix(self)->Matrix<Option<u32>, IntType>{
+        self.distances
+    }
+    pub fn distance(&self, pos: Position<IntType>)->Option<u32>{
+        self.distances.get(pos).flatten()
+    }
+    pub fn predecessors(&self)->&HashMap<Position<IntType>, Position<IntType>>{
+        &self.predecessors
+    }
+    /// Every reached position, sources included.
+    pub fn reached(&self)->impl Iterator<Item = Position<IntType>> + '_{
+        self.distances.enumerate().filter(|(_, distance)| distance.is_some()).map(|(pos, _)| pos)
+    }
+    /// A shortest path from the closest source to `pos`, both ends included.
+    pub fn path_to(&self, pos: Position<IntType>)->Option<Vec<Position<IntType>>>{
+        self.distance(pos).map(|_| walk_back(&self.predecessors, pos))
+    }
+}
+
+fn grid_neighbours<DataType, IntType: Coord, StorageType: GridStorage<DataType>>(
+    grid: &Matrix<DataType, IntType, StorageType>,
+    pos: Position<IntType>,
+)->impl Iterator<Item = (&DataType, Position<IntType>, &DataType)>{
+    let from = grid.tile(pos);
+    grid.neighbours(pos).into_iter().flatten()
+        .filter_map(move |next| Some((from?, next, grid.tile(next)?)))
+}
+
+/// [`bfs`] over the four neighbours of every tile. `can_step(from, to)` says which steps are allowed.
+pub fn grid_bfs<DataType, IntType: Coord, StorageType: GridStorage<DataType>>(
+    grid: &Matrix<DataType, IntType, StorageType>,
+    sources: impl IntoIterator<Item = Position<IntType>>,
+    can_step: impl Fn(&DataType, &DataType)->bool,
+)->GridDistances<IntType>{
+    let sources = sources.into_iter().filter(|&pos| grid.tile(pos).is_some());
+    let found = bfs(sources, |&pos| grid_neighbours(grid, pos)
+        .filter(|(from, _, to)| can_step(from, to))
+        .map(|(_, next, _)| next)
+        .collect::<Vec<_>>());
+    GridDistances::new(grid, found)
+}
+
+/// [`dijkstra`] over the four neighbours of every tile. `cost(from, to)` is the price of a step, `None` where it is not allowed.
+pub fn grid_dijkstra<DataType, IntType: Coord, StorageType: GridStorage<DataType>>(
+    grid: &Matrix<DataType, IntType, StorageType>,
+    sources: impl IntoIterator<Item = Position<IntType>>,
+    cost: impl Fn(&DataType, &DataType)->Option<u32>,
+)->GridDistances<IntType>{
+    let sources = sources.into_iter().filter(|&pos| grid.tile(pos).is_some());
+    let found = dijkstra(sources,
+        |&pos| grid_neighbours(grid, pos)
+            .filter(|(from, _, to)| cost(from, to).is_some())
+            .map(|(_, next, _)| next)
+            .collect::<Vec<_>>(),
+        |&from, &to| grid.tile(from).zip(grid.tile(to)).and_then(|(from, to)| cost(from, to)).unwrap_or_default());
+    GridDistances::new(grid, found)
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    fn maze()->Matrix<char>{
+        let rows = ["S..#", ".#.#", "...E"];
+        Matrix::new_from_square(rows.iter().map(|row| row.chars().collect()).collect())
+    }
+    #[test]
+    fn bfs_and_dijkstra_on_a_graph(){
+        let graph = HashMap::from([('a', vec![('b', 1), ('c', 5)]), ('b', vec![('c', 1)]), ('c', vec![('d', 1)]), ('x', vec![('d', 1)])]);
+        let neighbours = |state: &char| graph.get(state).into_iter().flatten().map(|(n,_)| *n).collect::<Vec<_>>();
+        let cost = |from: &char, to: &char| graph[from].iter().find(|(n,_)| n == to).unwrap().1;
+
+        let steps = bfs(['a'], neighbours);
+        assert_eq!((steps.distance(&'c'), steps.distance(&'d'), steps.distance(&'x')), (Some(1), Some(2), None));
+        assert_eq!(steps.path_to(&'d'), Some(vec!['a','c','d']));
+
+        let cheapest = dijkstra(['a'], neighbours, cost);
+        assert_eq!((cheapest.distance(&'c'), cheapest.distance(&'d')), (Some(2), Some(3)));
+        assert_eq!(cheapest.path_to(&'d'), Some(vec!['a','b','c','d']));
+        assert_eq!(cheapest.len(), 4);
+
+        // With more sources every state counts from the closest one.
+        let both = dijkstra(['a', 'x'], neighbours, cost);
+        assert_eq!((both.distance(&'d'), both.path_to(&'d')), (Some(1), Some(vec!['x','d'])));
+    }
+    #[test]
+    fn distances_on_a_grid(){
+        let maze = maze();
+        let open = |_: &char, to: &char| *to != '#';
+        let start = maze.find(&'S').unwrap();
+        let end = maze.find(&'E').unwrap();
+        let from_start = grid_bfs(&maze, [start], open);
+        assert_eq!(from_start.distance(end), Some(5));
+        assert_eq!(from_start.distance(Position::new(3, 0)), None);
+        assert_eq!(from_start.reached().count(), 9);
+        assert_eq!(from_start.path_to(end).map(|path| path.len()), Some(6));
+
+        let from_both = grid_bfs(&maze, [start, end], open);
+        assert_eq!(from_both.distance(Position::new(2, 2)), Some(1));
+        assert_eq!(from_both.distance(Position::new(2, 1)), Some(2));
+
+        // Stepping onto the end is expensive, everything else costs the same as in the BFS.
+        let weighted = grid_dijkstra(&maze, [start], |_, to| match to{ '#' => None, 'E' => Some(10), _ => Some(1) });
+        assert_eq!(weighted.distance(end), Some(14));
+        assert!(from_start.reached().all(|pos| pos == end || weighted.distance(pos) == from_start.distance(pos)));
+    }
+}
@@ -0,0 +1,44 @@
+use std::{any::TypeId, fmt::Display};
+
+use crate::parse::ParseError;
+
+/// One day of the calendar: how to parse the puzzle input and how to answer both parts.
+///
+/// Both parts get the parsed input by reference, so the input only has to be parsed once
+/// even when both parts are run (or when the same input is benchmarked over and over).
+pub trait Solution{
+    type Input;
+    type Answer1: Display + 'static;
+    type Answer2: Display + 'static;
+    fn parse(input: &str)->Result<Self::Input,ParseError>;
+    fn part1(input: &Self::Input)->Self::Answer1;
+    fn part2(input: &Self::Input)->Self::Answer2;
+}
+
+/// Answer type for a part that has no solver (yet).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+impl Display for Unsolved{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f,"not implemented")
+    }
+}
+
+/// Parses `input` and runs the requested part, giving back the answer as a string.
+/// Returns `Ok(None)` if `part` is neither 1 nor 2, or if that part is [`Unsolved`].
+pub fn run<S: Solution>(part: u8, input: &str)->Result<Option<String>,ParseError>{
+    let unsolved = match part{
+        1 => TypeId::of::<S::Answer1>() == TypeId::of::<Unsolved>(),
+        2 => TypeId::of::<S::Answer2>() == TypeId::of::<Unsolved>(),
+        _ => true
+    };
+    if unsolved{
+        return Ok(None);
+    }
+    let data = S::parse(input)?;
+    let answer = match part{
+        1 => S::part1(&data).to_string(),
+        _ => S::part2(&data).to_string(),
+    };
+    Ok(Some(answer))
+}
@@ -0,0 +1,181 @@
+use std::{collections::HashMap, fmt::Display};
+
+use crate::{coord::Coord, matrix::GetSet, position::Position};
+
+/// An unbounded grid that only stores the tiles that have been set.
+///
+/// Unlike [`crate::matrix::Matrix`] any position is valid, negative ones included, and the extent grows
+/// (or shrinks) with what is stored. `Display` draws the current extent, with a space for every empty tile.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<DataType, IntType = i64>{
+    data: HashMap<Position<IntType>, DataType>,
+    bounds: Option<(Position<IntType>, Position<IntType>)>
+}
+impl <DataType, IntType: Coord> Default for SparseGrid<DataType, IntType>{
+    fn default()->Self{
+        Self::new()
+    }
+}
+impl <DataType, IntType: Coord> SparseGrid<DataType, IntType>{
+    pub fn new()->Self{
+        Self{data: HashMap::new(), bounds: None}
+    }
+    /// Reads `rows` with the first row at `y = 0`, only keeping the tiles `keep` is interested in.
+    pub fn from_rows(rows: Vec<Vec<DataType>>, keep: fn(&DataType)->bool)->Self{
+        let mut grid = Self::new();
+        for (y,row) in rows.into_iter().enumerate(){
+            for (x,tile) in row.into_iter().enumerate(){
+                if !keep(&tile){
+                    continue;
+                }
+                if let (Some(x), Some(y)) = (IntType::from_usize(x), IntType::from_usize(y)){
+                    grid.insert(Position::new(x, y), tile);
+                }
+            }
+        }
+        grid
+    }
+    pub fn insert(&mut self, pos: Position<IntType>, value: DataType)->Option<DataType>{
+        self.bounds = Some(Self::grow(self.bounds, pos));
+        self.data.insert(pos, value)
+    }
+    pub fn remove(&mut self, pos: Position<IntType>)->Option<DataType>{
+        let removed = self.data.remove(&pos)?;
+        if let Some((min, max)) = self.bounds{
+            // Only a tile on the edge can make the extent shrink.
+            if pos.x() == min.x() || pos.y() == min.y() || pos.x() == max.x() || pos.y() == max.y(){
+                self.bounds = Self::extent(self.data.keys());
+            }
+        }
+        Some(removed)
+    }
+    pub fn tile(&self, pos: Position<IntType>)->Option<&DataType>{
+        self.data.get(&pos)
+    }
+    pub fn contains(&self, pos: Position<IntType>)->bool{
+        self.data.contains_key(&pos)
+    }
+    pub fn len(&self)->usize{
+        self.data.len()
+    }
+    pub fn is_empty(&self)->bool{
+        self.data.is_empty()
+    }
+    /// Every stored tile with its position, in no particular order.
+    pub fn iter(&self)->impl Iterator<Item = (Position<IntType>, &DataType)>{
+        self.data.iter().map(|(pos,val)| (*pos,val))
+    }
+    /// The smallest and largest corner of the area holding every stored tile, or `None` when nothing is stored.
+    pub fn bounds(&self)->Option<(Position<IntType>, Position<IntType>)>{
+        self.bounds
+    }
+    /// Saturates at `usize::MAX` for an extent wider than that.
+    pub fn width(&self)->usize{
+        self.bounds.map_or(0, |(min, max)| span(min.x(), max.x()))
+    }
+    /// Saturates at `usize::MAX` for an extent taller than that.
+    pub fn height(&self)->usize{
+        self.bounds.map_or(0, |(min, max)| span(min.y(), max.y()))
+    }
+    /// The positions above, below, left and right of `pos`, stored or not. `None` only where `IntType` runs out.
+    pub fn neighbours(&self, pos: Position<IntType>)->[Option<Position<IntType>>;4]{
+        pos.adjacent()
+    }
+    fn extent<'a>(positions: impl Iterator<Item = &'a Position<IntType>>)->Option<(Position<IntType>, Position<IntType>)>
+    where IntType: 'a{
+        positions.fold(None, |bounds, &pos| Some(Self::grow(bounds, pos)))
+    }
+    fn grow(bounds: Option<(Position<IntType>, Position<IntType>)>, pos: Position<IntType>)->(Position<IntType>, Position<IntType>){
+        match bounds{
+            Some((min, max)) => (Position::new(min.x().min(pos.x()), min.y().min(pos.y())), Position::new(max.x().max(pos.x()), max.y().max(pos.y()))),
+            None => (pos, pos)
+        }
+    }
+}
+impl <DataType: Copy, IntType: Coord> GetSet<Position<IntType>, DataType> for SparseGrid<DataType, IntType>{
+    fn get(&self, key: Position<IntType>)->Option<DataType>{
+        self.data.get(&key).copied()
+    }
+    fn set(&mut self, key: Position<IntType>, value: DataType){
+        self.insert(key, value);
+    }
+}
+/// The number of values from `min` to `max`, both included. Worked out in `i128`, as `max - min` can overflow `IntType`.
+fn span<IntType: Coord>(min: IntType, max: IntType)->usize{
+    let difference = match (min.to_i128(), max.to_i128()){
+        (Some(min), Some(max)) => max.checked_sub(min).and_then(|d| usize::try_from(d).ok()),
+        // Only unsigned values too big for `i128` get here, and those can not overflow going down.
+        _ => (max - min).to_usize()
+    };
+    difference.and_then(|d| d.checked_add(1)).unwrap_or(usize::MAX)
+}
+impl <DataType: Display, IntType: Coord> Display for SparseGrid<DataType, IntType>{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((min, max)) = self.bounds else {
+            return Ok(());
+        };
+        // Stops on the last row and column instead of stepping past them, which could overflow `IntType`.
+        let mut y = min.y();
+        loop{
+            let mut x = min.x();
+            loop{
+                match self.data.get(&Position::new(x, y)){
+                    Some(data) => write!(f,"{data}")?,
+                    None => write!(f," ")?
+                }
+                if x == max.x(){
+                    break;
+                }
+                x = x + IntType::ONE;
+            }
+            writeln!(f)?;
+            if y == max.y(){
+                return Ok(());
+            }
+            y = y + IntType::ONE;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests{
+    use super::*;
+
+    #[test]
+    fn grows_into_negative_coordinates(){
+        let mut grid: SparseGrid<char> = SparseGrid::from_rows(vec![vec!['#','.'],vec!['.','#']], |c| *c == '#');
+        assert_eq!(grid.bounds(), Some((Position::new(0,0), Position::new(1,1))));
+        grid.set(Position::new(-2,-1), 'R');
+        assert_eq!((grid.width(), grid.height(), grid.len()), (4, 3, 3));
+        assert_eq!(grid.to_string(), "R   \n  # \n   #\n");
+        assert_eq!(grid.get(Position::new(-2,-1)), Some('R'));
+        assert_eq!(grid.neighbours(Position::new(0,0))[0], Some(Position::new(0,-1)));
+    }
+    #[test]
+    fn shrinks_when_edges_are_removed(){
+        let mut grid: SparseGrid<u8> = SparseGrid::new();
+        assert_eq!((grid.width(), grid.to_string()), (0, String::new()));
+        grid.insert(Position::new(-5, 3), 1);
+        grid.insert(Position::new(2, 0), 2);
+        assert_eq!(grid.remove(Position::new(-5, 3)), Some(1));
+        assert_eq!(grid.bounds(), Some((Position::new(2,0), Position::new(2,0))));
+        assert_eq!(grid.remove(Position::new(2, 0)), Some(2));
+        assert_eq!((grid.bounds(), grid.is_empty()), (None, true));
+    }
+    #[test]
+    fn measures_extents_wider_than_the_index_type(){
+        let mut grid: SparseGrid<char, i8> = SparseGrid::new();
+        grid.insert(Position::new(-128, 0), 'a');
+        grid.insert(Position::new(127, -100), 'b');
+        grid.insert(Position::new(0, 100), 'c');
+        assert_eq!((grid.width(), grid.height()), (256, 201));
+        let drawn = grid.to_string();
+        assert_eq!((drawn.lines().count(), drawn.lines().next().map(str::len)), (201, Some(256)));
+        let mut wide: SparseGrid<char, u128> = SparseGrid::new();
+        wide.insert(Position::new(u128::MAX, 0), 'a');
+        wide.insert(Position::new(u128::MAX - 2, 0), 'b');
+        assert_eq!(wide.width(), 3);
+        wide.insert(Position::new(0, 0), 'c');
+        assert_eq!(wide.width(), usize::MAX);
+    }
+}